@@ -0,0 +1,163 @@
+use common::Answer;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Everything that can go wrong while loading a file with accepted answers.
+#[derive(Debug)]
+pub enum AnswersError {
+    Read { path: PathBuf, source: io::Error },
+    Parse { path: PathBuf, message: String },
+    InvalidValue { path: PathBuf, key: String },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Read { path, source } =>
+                write!(f, "Could not read answers file '{}': {}", path.display(), source),
+            AnswersError::Parse { path, message } =>
+                write!(f, "Could not parse answers file '{}': {}", path.display(), message),
+            AnswersError::InvalidValue { path, key } =>
+                write!(f, "Answer '{}' in '{}' must be an integer or a string", key, path.display()),
+        }
+    }
+}
+
+impl Error for AnswersError {}
+
+/// The accepted answers of one day, as stored in `answers/NN.toml`:
+///
+/// ```toml
+/// part1 = 35
+/// part2 = "a textual answer"
+/// ```
+///
+/// Answers are kept as text, so they can be compared to any kind of Answer.
+#[derive(Debug, Default, PartialEq)]
+pub struct AcceptedAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl AcceptedAnswers {
+    /// Load the answers of a day, a missing file simply means no answers were accepted yet.
+    pub fn load(answers_dir: &Path, day_nr: u8) -> Result<Self, AnswersError> {
+        let path = answers_dir.join(format!("{:02}.toml", day_nr));
+        match fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text, &path),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(AcceptedAnswers::default()),
+            Err(source) => Err(AnswersError::Read { path, source }),
+        }
+    }
+
+    pub fn parse(text: &str, path: &Path) -> Result<Self, AnswersError> {
+        let table: toml::Table = text.parse()
+            .map_err(|error: toml::de::Error| AnswersError::Parse {
+                path: path.to_path_buf(),
+                message: error.message().to_string()
+            })?;
+        let get_answer = |key: &str| match table.get(key) {
+            None => Ok(None),
+            Some(toml::Value::Integer(n)) => Ok(Some(n.to_string())),
+            Some(toml::Value::String(s)) => Ok(Some(s.clone())),
+            Some(_) => Err(AnswersError::InvalidValue { path: path.to_path_buf(), key: key.to_string() }),
+        };
+        Ok(AcceptedAnswers { part1: get_answer("part1")?, part2: get_answer("part2")? })
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+pub fn verify(accepted: Option<&str>, actual: &Answer) -> Verdict {
+    match accepted {
+        None => Verdict::Missing,
+        Some(expected) if expected == actual.to_string() => Verdict::Pass,
+        Some(expected) => Verdict::Fail { expected: expected.to_string() },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        // Arrange
+        let text = "part1 = 35\npart2 = \"FJKL\"\n";
+        let expected = AcceptedAnswers { part1: Some("35".to_string()), part2: Some("FJKL".to_string()) };
+
+        // Act
+        let actual = AcceptedAnswers::parse(text, Path::new("05.toml")).unwrap();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_only_part1() {
+        // Arrange
+        let text = "part1 = 35\n";
+
+        // Act
+        let actual = AcceptedAnswers::parse(text, Path::new("05.toml")).unwrap();
+
+        // Assert
+        assert_eq!(actual.get(1), Some("35"));
+        assert_eq!(actual.get(2), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_value() {
+        // Arrange
+        let text = "part1 = 3.5\n";
+
+        // Act
+        let actual = AcceptedAnswers::parse(text, Path::new("05.toml"));
+
+        // Assert
+        assert!(matches!(actual, Err(AnswersError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        // Arrange
+        let answers_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("does-not-exist");
+
+        // Act
+        let actual = AcceptedAnswers::load(&answers_dir, 5).unwrap();
+
+        // Assert
+        assert_eq!(actual, AcceptedAnswers::default());
+    }
+
+    #[test]
+    fn test_verify_pass() {
+        assert_eq!(verify(Some("35"), &Answer::from(35)), Verdict::Pass);
+    }
+
+    #[test]
+    fn test_verify_fail() {
+        assert_eq!(verify(Some("35"), &Answer::from(36)), Verdict::Fail { expected: "35".to_string() });
+    }
+
+    #[test]
+    fn test_verify_missing() {
+        assert_eq!(verify(None, &Answer::from(35)), Verdict::Missing);
+    }
+}
@@ -0,0 +1,127 @@
+use common::Solution;
+use std::hint::black_box;
+use std::str::Lines;
+use std::time::{Duration, Instant};
+
+/// Summary of the durations measured for a single step over all runs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Need at least one sample");
+        samples.sort();
+        let min = samples[0];
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+        let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+        Stats { min, median, mean }
+    }
+}
+
+/// The timings of all steps of a day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DayTimings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Time parsing, part 1 and part 2 of a solution separately, `nr_runs` times each.
+pub fn bench<S: Solution>(lines: Lines, nr_runs: usize) -> DayTimings {
+    let mut parse_samples: Vec<Duration> = Vec::with_capacity(nr_runs);
+    let mut part1_samples: Vec<Duration> = Vec::with_capacity(nr_runs);
+    let mut part2_samples: Vec<Duration> = Vec::with_capacity(nr_runs);
+
+    for _ in 0..nr_runs {
+        let start = Instant::now();
+        let input = S::parse(black_box(lines.clone()));
+        parse_samples.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&input));
+        part1_samples.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&input));
+        part2_samples.push(start.elapsed());
+    }
+
+    DayTimings {
+        parse: Stats::from_samples(parse_samples),
+        part1: Stats::from_samples(part1_samples),
+        part2: Stats::from_samples(part2_samples),
+    }
+}
+
+/// Format a duration with a unit that keeps the number readable, for example `12.34 ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1_000.0 {
+        format!("{:.0} ns", nanos)
+    } else if nanos < 1_000_000.0 {
+        format!("{:.2} µs", nanos / 1_000.0)
+    } else if nanos < 1_000_000_000.0 {
+        format!("{:.2} ms", nanos / 1_000_000.0)
+    } else {
+        format!("{:.2} s", nanos / 1_000_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_odd_number_of_samples() {
+        // Arrange
+        let samples = vec![Duration::from_millis(5), Duration::from_millis(1), Duration::from_millis(3)];
+        let expected = Stats {
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(3),
+            mean: Duration::from_millis(3),
+        };
+
+        // Act
+        let actual = Stats::from_samples(samples);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_stats_even_number_of_samples() {
+        // Arrange
+        let samples = vec![
+            Duration::from_millis(4), Duration::from_millis(1),
+            Duration::from_millis(2), Duration::from_millis(9)
+        ];
+        let expected = Stats {
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(3),
+            mean: Duration::from_millis(4),
+        };
+
+        // Act
+        let actual = Stats::from_samples(samples);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999 ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.35 µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50 ms");
+        assert_eq!(format_duration(Duration::from_millis(2_000)), "2.00 s");
+    }
+}
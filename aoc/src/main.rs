@@ -0,0 +1,247 @@
+mod answers;
+mod bench;
+mod registry;
+mod scaffold;
+
+use clap::{Parser, Subcommand};
+use common::{find_workspace_root, InputError, InputReader};
+use std::env;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+use crate::answers::{verify, AcceptedAnswers, Verdict};
+use crate::bench::{format_duration, DayTimings, Stats};
+use crate::registry::{find_day, Day, DAYS};
+use crate::scaffold::scaffold_day;
+
+/// Single entry point to run the Advent of Code 2023 solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or all days, and print the answers.
+    Run {
+        /// The day to run, for example 5.
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+
+        /// Only run this part instead of both.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Run every registered day.
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+
+        /// Read the input from this file instead of the input directory, use - for stdin.
+        #[arg(short, long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+    },
+
+    /// Run days on their real input and compare the results with the accepted answers.
+    Verify {
+        /// The day to verify, all days are verified when omitted.
+        day: Option<u8>,
+
+        /// Directory with the accepted answers, defaults to `answers` in the workspace root.
+        #[arg(long)]
+        answers_dir: Option<PathBuf>,
+    },
+
+    /// Time parsing, part 1 and part 2 of days on their real input.
+    Bench {
+        /// The day to benchmark, all days are benchmarked when omitted.
+        day: Option<u8>,
+
+        /// How many times each step is run.
+        #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+    },
+
+    /// Create the crate for a new day from day-template and register it.
+    New {
+        /// The day to create, for example 9.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run { day, part, all, input } => run(day, part, all, input),
+        Command::Verify { day, answers_dir } => verify_days(day, answers_dir),
+        Command::Bench { day, runs } => bench_days(day, runs as usize),
+        Command::New { day } => new_day(day),
+    }
+}
+
+fn run(day_nr: Option<u8>, part: Option<u8>, all: bool, input: Option<PathBuf>) -> ExitCode {
+    let days: Vec<&Day> = match select_days(if all { None } else { day_nr }) {
+        Some(days) => days,
+        None => return ExitCode::FAILURE,
+    };
+
+    let parts: Vec<u8> = part.map_or(vec![1, 2], |part| vec![part]);
+    for day in days {
+        let input_reader: InputReader = match read_input(day.nr, input.as_ref()) {
+            Ok(input_reader) => input_reader,
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitCode::FAILURE;
+            }
+        };
+        for part in parts.iter() {
+            println!("Day {:02} Part {}: {}", day.nr, part, day.solve(*part, input_reader.lines()));
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn verify_days(day_nr: Option<u8>, answers_dir: Option<PathBuf>) -> ExitCode {
+    let days: Vec<&Day> = match select_days(day_nr) {
+        Some(days) => days,
+        None => return ExitCode::FAILURE,
+    };
+    let answers_dir: PathBuf = match answers_dir.map_or_else(default_answers_dir, Ok) {
+        Ok(answers_dir) => answers_dir,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut nr_passed, mut nr_failed, mut nr_missing) = (0, 0, 0);
+    for day in days {
+        let accepted = match AcceptedAnswers::load(&answers_dir, day.nr) {
+            Ok(accepted) => accepted,
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitCode::FAILURE;
+            }
+        };
+        let input_reader: InputReader = match InputReader::new(day.nr) {
+            Ok(input_reader) => input_reader,
+            Err(error) => {
+                println!("Day {:02}: MISSING input ({})", day.nr, error);
+                nr_missing += 2;
+                continue;
+            }
+        };
+        for part in [1, 2] {
+            let actual = day.solve(part, input_reader.lines());
+            match verify(accepted.get(part), &actual) {
+                Verdict::Pass => {
+                    println!("Day {:02} Part {}: PASS {}", day.nr, part, actual);
+                    nr_passed += 1;
+                }
+                Verdict::Fail { expected } => {
+                    println!("Day {:02} Part {}: FAIL expected {}, got {}", day.nr, part, expected, actual);
+                    nr_failed += 1;
+                }
+                Verdict::Missing => {
+                    println!("Day {:02} Part {}: MISSING answer, got {}", day.nr, part, actual);
+                    nr_missing += 1;
+                }
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", nr_passed, nr_failed, nr_missing);
+    if nr_failed > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+fn bench_days(day_nr: Option<u8>, nr_runs: usize) -> ExitCode {
+    let days: Vec<&Day> = match select_days(day_nr) {
+        Some(days) => days,
+        None => return ExitCode::FAILURE,
+    };
+
+    println!("{} runs per step, min / median / mean", nr_runs);
+    println!("{:<6} {:<7} {:>12} {:>12} {:>12}", "Day", "Step", "Min", "Median", "Mean");
+    let mut total_mean = Duration::ZERO;
+    for day in days {
+        let input_reader: InputReader = match InputReader::new(day.nr) {
+            Ok(input_reader) => input_reader,
+            Err(error) => {
+                println!("{:<6} skipped: {}", format!("{:02}", day.nr), error);
+                continue;
+            }
+        };
+        let timings: DayTimings = (day.bench)(input_reader.lines(), nr_runs);
+        for (step, stats) in [("parse", timings.parse), ("part 1", timings.part1), ("part 2", timings.part2)] {
+            print_stats_row(day.nr, step, &stats);
+            total_mean += stats.mean;
+        }
+    }
+    println!("{:<6} {:<7} {:>12} {:>12} {:>12}", "Total", "", "", "", format_duration(total_mean));
+
+    ExitCode::SUCCESS
+}
+
+fn print_stats_row(day_nr: u8, step: &str, stats: &Stats) {
+    println!("{:<6} {:<7} {:>12} {:>12} {:>12}",
+             format!("{:02}", day_nr),
+             step,
+             format_duration(stats.min),
+             format_duration(stats.median),
+             format_duration(stats.mean));
+}
+
+fn new_day(day_nr: u8) -> ExitCode {
+    let result = env::current_dir()
+        .map_err(InputError::CurrentDir)
+        .and_then(|current_dir| find_workspace_root(&current_dir));
+    let workspace_root: PathBuf = match result {
+        Ok(workspace_root) => workspace_root,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match scaffold_day(&workspace_root, day_nr) {
+        Ok(day_dir) => {
+            println!("Created {}", day_dir.display());
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Get the requested day, or all days when no day is given.
+/// Reports the problem and returns None for a day without a solution.
+fn select_days(day_nr: Option<u8>) -> Option<Vec<&'static Day>> {
+    match day_nr {
+        None => Some(DAYS.iter().collect()),
+        Some(day_nr) => match find_day(day_nr) {
+            Some(day) => Some(vec![day]),
+            None => {
+                eprintln!("Day {} has no solution yet", day_nr);
+                None
+            }
+        },
+    }
+}
+
+fn read_input(day_nr: u8, input: Option<&PathBuf>) -> Result<InputReader, InputError> {
+    match input {
+        Some(path) if path.as_os_str() == "-" => InputReader::from_stdin(),
+        Some(path) => InputReader::from_path(path),
+        None => InputReader::new(day_nr),
+    }
+}
+
+fn default_answers_dir() -> Result<PathBuf, InputError> {
+    let current_dir = env::current_dir().map_err(InputError::CurrentDir)?;
+    Ok(find_workspace_root(&current_dir)?.join("answers"))
+}
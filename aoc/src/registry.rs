@@ -0,0 +1,82 @@
+use common::{Answer, Solution};
+use std::str::Lines;
+use crate::bench::{bench, DayTimings};
+
+/// Solves one part of a puzzle, parsing included.
+pub type PartSolver = fn(Lines) -> Answer;
+
+/// Times the steps of a day over the given number of runs.
+pub type Benchmark = fn(Lines, usize) -> DayTimings;
+
+/// All we need to know about a day to run it: its number, a solver per part and a benchmark.
+pub struct Day {
+    pub nr: u8,
+    pub part1: PartSolver,
+    pub part2: PartSolver,
+    pub bench: Benchmark,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        Day { nr: S::DAY, part1: S::solve_part1, part2: S::solve_part2, bench: bench::<S> }
+    }
+
+    pub fn solve(&self, part: u8, lines: Lines) -> Answer {
+        match part {
+            1 => (self.part1)(lines),
+            2 => (self.part2)(lines),
+            _ => panic!("There is no part {}", part),
+        }
+    }
+}
+
+/// The days in the order they should be run.
+pub static DAYS: &[Day] = &[
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+];
+
+pub fn find_day(nr: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.nr == nr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_sorted_and_unique() {
+        // Arrange
+        let nrs: Vec<u8> = DAYS.iter().map(|day| day.nr).collect();
+
+        // Act
+        let is_strictly_increasing = nrs.windows(2).all(|pair| pair[0] < pair[1]);
+
+        // Assert
+        assert!(is_strictly_increasing);
+    }
+
+    #[test]
+    fn test_find_day() {
+        // Arrange
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+        let expected = Answer::from(288);
+
+        // Act
+        let actual = find_day(6).unwrap().solve(1, input.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_find_day_unknown() {
+        assert!(find_day(26).is_none());
+    }
+}
@@ -0,0 +1,259 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The day the files in `day-template` are written for, this is replaced by the new day.
+const TEMPLATE_DAY_NR: u8 = 8;
+
+/// Everything that can go wrong while scaffolding a new day.
+#[derive(Debug)]
+pub enum ScaffoldError {
+    DayExists(PathBuf),
+    Io { path: PathBuf, source: io::Error },
+    InsertionPointNotFound { path: PathBuf, what: &'static str },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::DayExists(path) =>
+                write!(f, "'{}' already exists, refusing to overwrite it", path.display()),
+            ScaffoldError::Io { path, source } =>
+                write!(f, "Could not access '{}': {}", path.display(), source),
+            ScaffoldError::InsertionPointNotFound { path, what } =>
+                write!(f, "Could not find where to add the {} in '{}'", what, path.display()),
+        }
+    }
+}
+
+impl Error for ScaffoldError {}
+
+/// Create the crate for a new day from `day-template`, register it in the workspace and
+/// the runner, and create an empty input file. Returns the directory of the new crate.
+pub fn scaffold_day(workspace_root: &Path, day_nr: u8) -> Result<PathBuf, ScaffoldError> {
+    let day_dir = workspace_root.join(crate_name(day_nr));
+    if day_dir.exists() {
+        return Err(ScaffoldError::DayExists(day_dir));
+    }
+
+    // Do all edits in memory first, so we don't leave a half registered day behind
+    // when one of the files doesn't look like we expect.
+    let workspace_manifest_path = workspace_root.join("Cargo.toml");
+    let workspace_manifest = add_workspace_member(&read(&workspace_manifest_path)?, day_nr)
+        .ok_or(insertion_point_not_found(&workspace_manifest_path, "workspace member"))?;
+    let runner_manifest_path = workspace_root.join("aoc").join("Cargo.toml");
+    let runner_manifest = add_runner_dependency(&read(&runner_manifest_path)?, day_nr)
+        .ok_or(insertion_point_not_found(&runner_manifest_path, "dependency"))?;
+    let registry_path = workspace_root.join("aoc").join("src").join("registry.rs");
+    let registry = add_registry_entry(&read(&registry_path)?, day_nr)
+        .ok_or(insertion_point_not_found(&registry_path, "registry entry"))?;
+
+    // The input file is created first, so a missing or read-only input directory fails
+    // before anything is registered.
+    let input_dir = workspace_root.join("input");
+    fs::create_dir_all(&input_dir).map_err(|source| io_error(&input_dir, source))?;
+    let input_path = input_dir.join(format!("{:02}.txt", day_nr));
+    if !input_path.exists() {
+        write(&input_path, "")?;
+    }
+
+    copy_template(&workspace_root.join("day-template"), &day_dir, day_nr)?;
+    write(&workspace_manifest_path, &workspace_manifest)?;
+    write(&runner_manifest_path, &runner_manifest)?;
+    write(&registry_path, &registry)?;
+
+    Ok(day_dir)
+}
+
+fn crate_name(day_nr: u8) -> String {
+    format!("day{:02}", day_nr)
+}
+
+fn type_name(day_nr: u8) -> String {
+    format!("Day{:02}", day_nr)
+}
+
+fn copy_template(template_dir: &Path, target_dir: &Path, day_nr: u8) -> Result<(), ScaffoldError> {
+    fs::create_dir_all(target_dir).map_err(|source| io_error(target_dir, source))?;
+    let entries = fs::read_dir(template_dir).map_err(|source| io_error(template_dir, source))?;
+    for entry in entries {
+        let entry = entry.map_err(|source| io_error(template_dir, source))?;
+        let source_path = entry.path();
+        let target_path = target_dir.join(entry.file_name());
+        if source_path.is_dir() {
+            copy_template(&source_path, &target_path, day_nr)?;
+        } else {
+            write(&target_path, &instantiate_template(&read(&source_path)?, day_nr))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Replace all references to the day the template was written for by the new day.
+pub fn instantiate_template(text: &str, day_nr: u8) -> String {
+    text
+        .replace(&crate_name(TEMPLATE_DAY_NR), &crate_name(day_nr))
+        .replace(&type_name(TEMPLATE_DAY_NR), &type_name(day_nr))
+        .replace(&format!("const DAY: u8 = {};", TEMPLATE_DAY_NR), &format!("const DAY: u8 = {};", day_nr))
+}
+
+/// Add the day to the workspace members, right after the last day before it.
+pub fn add_workspace_member(manifest: &str, day_nr: u8) -> Option<String> {
+    insert_line_after_last_day(manifest, day_nr, |line| {
+        let name = line.trim().trim_end_matches(',').trim_matches(|c| c == '\'' || c == '"');
+        parse_day_nr(name.strip_prefix("day")?)
+    }, format!("   \"{}\",", crate_name(day_nr)))
+}
+
+pub fn add_runner_dependency(manifest: &str, day_nr: u8) -> Option<String> {
+    insert_line_after_last_day(manifest, day_nr, |line| {
+        let (name, _) = line.split_once(" = ")?;
+        parse_day_nr(name.strip_prefix("day")?)
+    }, format!("{} = {{ path = \"../{}\" }}", crate_name(day_nr), crate_name(day_nr)))
+}
+
+pub fn add_registry_entry(source: &str, day_nr: u8) -> Option<String> {
+    insert_line_after_last_day(source, day_nr, |line| {
+        let rest = line.trim().strip_prefix("Day::of::<day")?;
+        parse_day_nr(rest.get(..2)?)
+    }, format!("    Day::of::<{}::{}>(),", crate_name(day_nr), type_name(day_nr)))
+}
+
+fn parse_day_nr(text: &str) -> Option<u8> {
+    if text.len() == 2 { text.parse().ok() } else { None }
+}
+
+/// Insert `new_line` after the last line for which `get_day_nr` returns a day before `day_nr`.
+/// When all days are after `day_nr`, it is inserted before the first one.
+fn insert_line_after_last_day(text: &str, day_nr: u8, get_day_nr: impl Fn(&str) -> Option<u8>,
+                              new_line: String) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let day_lines: Vec<(usize, u8)> = lines.iter()
+        .enumerate()
+        .filter_map(|(index, line)| get_day_nr(line).map(|nr| (index, nr)))
+        .collect();
+    let insert_index = match day_lines.iter().rfind(|(_, nr)| *nr < day_nr) {
+        Some((index, _)) => index + 1,
+        None => day_lines.first()?.0,
+    };
+    lines.insert(insert_index, &new_line);
+
+    let mut result = lines.join("\n");
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    Some(result)
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| io_error(path, source))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    fs::write(path, contents).map_err(|source| io_error(path, source))
+}
+
+fn io_error(path: &Path, source: io::Error) -> ScaffoldError {
+    ScaffoldError::Io { path: path.to_path_buf(), source }
+}
+
+fn insertion_point_not_found(path: &Path, what: &'static str) -> ScaffoldError {
+    ScaffoldError::InsertionPointNotFound { path: path.to_path_buf(), what }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instantiate_template() {
+        // Arrange
+        let text = "use day08::Day08;\nimpl Solution for Day08 {\n    const DAY: u8 = 8;\n";
+        let expected = "use day12::Day12;\nimpl Solution for Day12 {\n    const DAY: u8 = 12;\n";
+
+        // Act
+        let actual = instantiate_template(text, 12);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_add_workspace_member() {
+        // Arrange
+        let manifest = "[workspace]\nmembers = [\n   'day01',\n   \"day02\",\n   'common',\n]\n";
+        let expected = "[workspace]\nmembers = [\n   'day01',\n   \"day02\",\n   \"day03\",\n   'common',\n]\n";
+
+        // Act
+        let actual = add_workspace_member(manifest, 3).unwrap();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_add_workspace_member_before_all_days() {
+        // Arrange
+        let manifest = "members = [\n   \"day02\",\n]";
+        let expected = "members = [\n   \"day01\",\n   \"day02\",\n]";
+
+        // Act
+        let actual = add_workspace_member(manifest, 1).unwrap();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_add_workspace_member_without_days() {
+        assert_eq!(add_workspace_member("members = [\n   'common',\n]", 1), None);
+    }
+
+    #[test]
+    fn test_add_runner_dependency() {
+        // Arrange
+        let manifest = "common = { path = \"../common\" }\nday01 = { path = \"../day01\" }\ntoml = \"0.8\"\n";
+        let expected = "common = { path = \"../common\" }\nday01 = { path = \"../day01\" }\n\
+                        day09 = { path = \"../day09\" }\ntoml = \"0.8\"\n";
+
+        // Act
+        let actual = add_runner_dependency(manifest, 9).unwrap();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_add_registry_entry() {
+        // Arrange
+        let source = "pub static DAYS: &[Day] = &[\n    Day::of::<day01::Day01>(),\n];\n";
+        let expected = "pub static DAYS: &[Day] = &[\n    Day::of::<day01::Day01>(),\n    Day::of::<day10::Day10>(),\n];\n";
+
+        // Act
+        let actual = add_registry_entry(source, 10).unwrap();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_scaffold_day_exists() {
+        // Arrange
+        let workspace_root = std::env::temp_dir().join(format!("aoc-scaffold-test-{}", std::process::id()));
+        let day_dir = workspace_root.join("day03");
+        fs::create_dir_all(&day_dir).unwrap();
+        fs::write(workspace_root.join("Cargo.toml"), "members = [\n   \"day03\",\n]\n").unwrap();
+
+        // Act
+        let actual = scaffold_day(&workspace_root, 3);
+
+        // Assert
+        let manifest = fs::read_to_string(workspace_root.join("Cargo.toml")).unwrap();
+        fs::remove_dir_all(&workspace_root).unwrap();
+        assert!(matches!(actual, Err(ScaffoldError::DayExists(path)) if path == day_dir));
+        assert_eq!(manifest, "members = [\n   \"day03\",\n]\n");
+    }
+}
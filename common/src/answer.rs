@@ -0,0 +1,106 @@
+use num_bigint::BigInt;
+use std::fmt;
+
+/// The answer to one part of a puzzle. Days return different kinds of answers, wrapping them
+/// in Answer lets the runner, benchmarks and verifiers treat every day the same way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    BigInteger(BigInt),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::BigInteger(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        Answer::from(BigInt::from(n))
+    }
+}
+
+/// Big integers that fit in an i128 become a regular Integer, so the same number always
+/// ends up as the same Answer, no matter which type a day used to calculate it.
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        match i128::try_from(&n) {
+            Ok(small) => Answer::Integer(small),
+            Err(_) => Answer::BigInteger(n),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_types_are_equal() {
+        assert_eq!(Answer::from(42u32), Answer::from(42i64));
+    }
+
+    #[test]
+    fn test_big_integer_that_fits_is_integer() {
+        // Arrange
+        let big = BigInt::from(12345);
+        let expected = Answer::Integer(12345);
+
+        // Act
+        let actual = Answer::from(big);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_big_integer_that_does_not_fit() {
+        // Arrange
+        let big: BigInt = BigInt::from(u128::MAX) * 2;
+
+        // Act
+        let actual = Answer::from(big.clone());
+
+        // Assert
+        assert_eq!(actual, Answer::BigInteger(big));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-7).to_string(), "-7");
+        assert_eq!(Answer::from(u128::MAX).to_string(), "340282366920938463463374607431768211455");
+        assert_eq!(Answer::from("FJKL").to_string(), "FJKL");
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{Integer, OverflowError, Range};
+use std::fmt;
+use std::ops::Add;
+
+/// An axis-aligned box in `N` dimensions, with a `Range` per axis. A `Cuboid<T, 2>` is a
+/// rectangle, a `Cuboid<T, 3>` a brick.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T, const N: usize>
+where
+    T: PartialOrd + Clone,
+{
+    axes: [Range<T>; N],
+}
+
+impl<T, const N: usize> fmt::Debug for Cuboid<T, N>
+where
+    T: PartialOrd + Clone + Add<Output = T> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, axis) in self.axes.iter().enumerate() {
+            if i > 0 {
+                write!(f, " x ")?;
+            }
+            write!(f, "{:?}", axis)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Integer, const N: usize> Cuboid<T, N> {
+    pub fn new(axes: [Range<T>; N]) -> Self {
+        Cuboid { axes }
+    }
+
+    pub fn axes(&self) -> &[Range<T>; N] {
+        &self.axes
+    }
+
+    pub fn axis(&self, axis: usize) -> Range<T> {
+        self.axes[axis].clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|axis| axis.is_empty())
+    }
+
+    /// The number of points in the box, the product of the lengths of all axes. Fails when it
+    /// doesn't fit in `T`.
+    pub fn volume(&self) -> Result<T, OverflowError> {
+        if self.is_empty() {
+            return Ok(T::default());
+        }
+        self.axes.iter().try_fold(T::from(1), |volume, axis| volume.try_mul(&axis.length()))
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.axes.iter().zip(point).all(|(axis, n)| axis.contains(n))
+    }
+
+    /// Check if this box overlaps with another box, boxes that only touch don't overlap.
+    pub fn overlaps(&self, other: &Cuboid<T, N>) -> bool {
+        self.axes.iter().zip(other.axes.iter()).all(|(a, b)| a.overlaps(b))
+    }
+
+    /// The part of this box that is also in `other`, None when they don't overlap.
+    pub fn intersection(&self, other: &Cuboid<T, N>) -> Option<Cuboid<T, N>> {
+        let mut axes = self.axes.clone();
+        for (axis, other_axis) in axes.iter_mut().zip(other.axes.iter()) {
+            *axis = axis.intersection(other_axis)?;
+        }
+        Some(Cuboid { axes })
+    }
+
+    /// Remove `other` from this box. The result is a list of at most `2 * N` disjoint boxes that
+    /// together cover exactly the part of `self` outside `other`.
+    pub fn subtract(&self, other: &Cuboid<T, N>) -> Vec<Cuboid<T, N>> {
+        let Some(overlap) = self.intersection(other) else {
+            return vec![self.clone()];
+        };
+
+        // Per axis, cut off the slabs before and after the overlap, and continue with what's left.
+        // The slabs lie within `self`, so their lengths fit in `T`.
+        let mut result: Vec<Cuboid<T, N>> = Vec::new();
+        let mut remaining = self.clone();
+        for axis in 0..N {
+            let current = remaining.axes[axis].clone();
+            let kept = overlap.axes[axis].clone();
+            for slab in [
+                Range::from_start_end(current.start(), kept.start()),
+                Range::from_start_end(kept.end(), current.end()),
+            ] {
+                if !slab.is_empty() {
+                    let mut axes = remaining.axes.clone();
+                    axes[axis] = slab;
+                    result.push(Cuboid { axes });
+                }
+            }
+            remaining.axes[axis] = kept;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_cuboid<const N: usize>(bounds: [(i64, i64); N]) -> Cuboid<i64, N> {
+        Cuboid::new(bounds.map(|(start, end)| Range::from_start_end(start, end)))
+    }
+
+    #[test]
+    fn test_volume() {
+        assert_eq!(create_cuboid([(0, 2), (0, 3), (1, 5)]).volume(), Ok(24));
+        assert_eq!(create_cuboid([(0, 2), (3, 3)]).volume(), Ok(0));
+        assert!(create_cuboid([(0, 1 << 32), (0, 1 << 32)]).volume().is_err());
+        assert!(create_cuboid([(0, 2), (3, 3)]).is_empty());
+    }
+
+    #[test]
+    fn test_contains() {
+        // Arrange
+        let cuboid = create_cuboid([(0, 2), (0, 3)]);
+
+        // Act & Assert
+        assert!(cuboid.contains([1, 2]));
+        assert!(!cuboid.contains([2, 2]));
+        assert!(!cuboid.contains([1, -1]));
+    }
+
+    #[test]
+    fn test_overlaps_and_intersection() {
+        // Arrange
+        let a = create_cuboid([(0, 4), (0, 4)]);
+        let b = create_cuboid([(2, 6), (3, 8)]);
+        let touching = create_cuboid([(4, 6), (0, 4)]);
+
+        // Act & Assert
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&touching));
+        assert_eq!(a.intersection(&b), Some(create_cuboid([(2, 4), (3, 4)])));
+        assert_eq!(a.intersection(&touching), None);
+    }
+
+    #[test]
+    fn test_subtract_inner_box() {
+        // Arrange
+        let outer = create_cuboid([(0, 3), (0, 3), (0, 3)]);
+        let inner = create_cuboid([(1, 2), (1, 2), (1, 2)]);
+
+        // Act
+        let actual = outer.subtract(&inner);
+
+        // Assert
+        assert_eq!(actual.len(), 6);
+        assert_eq!(actual.iter().map(|cuboid| cuboid.volume().unwrap()).sum::<i64>(), 26);
+        for (i, a) in actual.iter().enumerate() {
+            assert!(actual[i + 1..].iter().all(|b| !a.overlaps(b)));
+            assert!(!a.overlaps(&inner));
+        }
+    }
+
+    #[test]
+    fn test_subtract_corner() {
+        // Arrange
+        let a = create_cuboid([(0, 4), (0, 4)]);
+        let b = create_cuboid([(2, 6), (2, 6)]);
+        let expected = vec![create_cuboid([(0, 2), (0, 4)]), create_cuboid([(2, 4), (0, 2)])];
+
+        // Act
+        let actual = a.subtract(&b);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_subtract_without_overlap() {
+        // Arrange
+        let a = create_cuboid([(0, 4)]);
+        let b = create_cuboid([(4, 6)]);
+
+        // Act
+        let actual = a.subtract(&b);
+
+        // Assert
+        assert_eq!(actual, vec![a]);
+    }
+
+    #[test]
+    fn test_subtract_everything() {
+        assert_eq!(create_cuboid([(1, 2), (1, 2)]).subtract(&create_cuboid([(0, 4), (0, 4)])), vec![]);
+    }
+}
@@ -0,0 +1,432 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A directed graph with nodes of type `N`, identified by their value, and edges with a weight
+/// of type `E`. Nodes and edges keep the order in which they were added, so all traversals are
+/// deterministic.
+#[derive(Debug, Clone)]
+pub struct Graph<N, E> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    edges: Vec<Vec<(usize, E)>>,
+}
+
+/// Returned by `Graph::topological_sort` when the graph contains a cycle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CycleError<N> {
+    /// The nodes on the cycle, the first node is repeated at the end.
+    pub cycle: Vec<N>,
+}
+
+impl<N: Debug> fmt::Display for CycleError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cycle: Vec<String> = self.cycle.iter().map(|node| format!("{:?}", node)).collect();
+        write!(f, "Graph contains a cycle: {}", cycle.join(" -> "))
+    }
+}
+
+impl<N: Debug> Error for CycleError<N> {}
+
+impl<N: Clone + Eq + Hash, E> Default for Graph<N, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Clone + Eq + Hash, E> Graph<N, E> {
+    pub fn new() -> Self {
+        Graph { nodes: Vec::new(), indices: HashMap::new(), edges: Vec::new() }
+    }
+
+    /// Add a node, returns false when it was already part of the graph.
+    pub fn add_node(&mut self, node: N) -> bool {
+        if self.indices.contains_key(&node) {
+            return false;
+        }
+        self.insert_node(node);
+        true
+    }
+
+    /// Add an edge from `from` to `to`, the nodes are added when they are not part of the graph yet.
+    pub fn add_edge(&mut self, from: N, to: N, weight: E) {
+        let from_index = self.insert_node(from);
+        let to_index = self.insert_node(to);
+        self.edges[from_index].push((to_index, weight));
+    }
+
+    pub fn contains_node(&self, node: &N) -> bool {
+        self.indices.contains_key(node)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(|edges| edges.len()).sum()
+    }
+
+    /// The nodes reachable from `node` through a single edge, with the weight of that edge.
+    pub fn neighbours(&self, node: &N) -> impl Iterator<Item = (&N, &E)> + '_ {
+        self.indices
+            .get(node)
+            .into_iter()
+            .flat_map(|index| self.edges[*index].iter().map(|(to, weight)| (&self.nodes[*to], weight)))
+    }
+
+    /// The weight of the first edge from `from` to `to`.
+    pub fn edge(&self, from: &N, to: &N) -> Option<&E> {
+        self.neighbours(from).find(|(node, _)| *node == to).map(|(_, weight)| weight)
+    }
+
+    /// All nodes reachable from `start` in breadth-first order, `start` included.
+    pub fn bfs(&self, start: &N) -> Vec<&N> {
+        let Some(start_index) = self.indices.get(start) else {
+            return Vec::new();
+        };
+        let mut is_visited = vec![false; self.nodes.len()];
+        is_visited[*start_index] = true;
+        let mut order: Vec<&N> = Vec::new();
+        let mut queue: VecDeque<usize> = VecDeque::from([*start_index]);
+        while let Some(current) = queue.pop_front() {
+            order.push(&self.nodes[current]);
+            for (to, _) in self.edges[current].iter() {
+                if !is_visited[*to] {
+                    is_visited[*to] = true;
+                    queue.push_back(*to);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// All nodes reachable from `start` in depth-first pre-order, `start` included.
+    pub fn dfs(&self, start: &N) -> Vec<&N> {
+        let Some(start_index) = self.indices.get(start) else {
+            return Vec::new();
+        };
+        let mut is_visited = vec![false; self.nodes.len()];
+        let mut order: Vec<&N> = Vec::new();
+        let mut stack: Vec<usize> = vec![*start_index];
+        while let Some(current) = stack.pop() {
+            if is_visited[current] {
+                continue;
+            }
+            is_visited[current] = true;
+            order.push(&self.nodes[current]);
+            // Push in reverse, so the first neighbour is visited first.
+            for (to, _) in self.edges[current].iter().rev() {
+                if !is_visited[*to] {
+                    stack.push(*to);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Order the nodes so every edge goes from an earlier to a later node.
+    /// When that is impossible, one of the cycles in the graph is returned as error.
+    pub fn topological_sort(&self) -> Result<Vec<&N>, CycleError<N>> {
+        let mut in_degrees = vec![0; self.nodes.len()];
+        for (to, _) in self.edges.iter().flatten() {
+            in_degrees[*to] += 1;
+        }
+
+        let mut queue: VecDeque<usize> = (0..self.nodes.len()).filter(|index| in_degrees[*index] == 0).collect();
+        let mut order: Vec<&N> = Vec::new();
+        while let Some(current) = queue.pop_front() {
+            order.push(&self.nodes[current]);
+            for (to, _) in self.edges[current].iter() {
+                in_degrees[*to] -= 1;
+                if in_degrees[*to] == 0 {
+                    queue.push_back(*to);
+                }
+            }
+        }
+
+        if order.len() == self.nodes.len() {
+            Ok(order)
+        } else {
+            Err(CycleError { cycle: self.find_cycle(&in_degrees) })
+        }
+    }
+
+    /// Every node that wasn't sorted still has a predecessor that wasn't sorted, so walking back
+    /// over those predecessors has to end up in a cycle.
+    fn find_cycle(&self, in_degrees: &[usize]) -> Vec<N> {
+        let mut predecessors: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        for (from, edges) in self.edges.iter().enumerate().filter(|(from, _)| in_degrees[*from] > 0) {
+            for (to, _) in edges.iter() {
+                predecessors[*to].get_or_insert(from);
+            }
+        }
+
+        let mut position_on_walk: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut walk: Vec<usize> = Vec::new();
+        let mut current = (0..self.nodes.len()).find(|index| in_degrees[*index] > 0).unwrap();
+        while position_on_walk[current].is_none() {
+            position_on_walk[current] = Some(walk.len());
+            walk.push(current);
+            current = predecessors[current].unwrap();
+        }
+
+        // The walk went backwards, reverse it and start at the node that was added first.
+        let mut cycle_indices: Vec<usize> = walk[position_on_walk[current].unwrap()..].iter().rev().copied().collect();
+        let first = (0..cycle_indices.len()).min_by_key(|position| cycle_indices[*position]).unwrap();
+        cycle_indices.rotate_left(first);
+        let mut cycle: Vec<N> = cycle_indices.iter().map(|index| self.nodes[*index].clone()).collect();
+        cycle.push(cycle[0].clone());
+        cycle
+    }
+
+    /// Group the nodes in strongly connected components: sets of nodes that can all reach each other.
+    /// Uses Kosaraju's algorithm, components come in topological order of the condensed graph.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<&N>> {
+        // First pass: order the nodes by the moment their depth-first search finished.
+        let mut is_visited = vec![false; self.nodes.len()];
+        let mut finish_order: Vec<usize> = Vec::new();
+        for start in 0..self.nodes.len() {
+            if is_visited[start] {
+                continue;
+            }
+            is_visited[start] = true;
+            let mut stack: Vec<(usize, usize)> = vec![(start, 0)];
+            while let Some((current, next_edge)) = stack.pop() {
+                match self.edges[current].get(next_edge) {
+                    Some((to, _)) => {
+                        stack.push((current, next_edge + 1));
+                        if !is_visited[*to] {
+                            is_visited[*to] = true;
+                            stack.push((*to, 0));
+                        }
+                    }
+                    None => finish_order.push(current),
+                }
+            }
+        }
+
+        // Second pass: search the reversed graph, in reverse finish order.
+        let mut reversed: Vec<Vec<usize>> = vec![Vec::new(); self.nodes.len()];
+        for (from, edges) in self.edges.iter().enumerate() {
+            for (to, _) in edges.iter() {
+                reversed[*to].push(from);
+            }
+        }
+        let mut is_assigned = vec![false; self.nodes.len()];
+        let mut components: Vec<Vec<&N>> = Vec::new();
+        for start in finish_order.into_iter().rev() {
+            if is_assigned[start] {
+                continue;
+            }
+            is_assigned[start] = true;
+            let mut component: Vec<&N> = Vec::new();
+            let mut stack: Vec<usize> = vec![start];
+            while let Some(current) = stack.pop() {
+                component.push(&self.nodes[current]);
+                for from in reversed[current].iter() {
+                    if !is_assigned[*from] {
+                        is_assigned[*from] = true;
+                        stack.push(*from);
+                    }
+                }
+            }
+            components.push(component);
+        }
+
+        components
+    }
+
+    fn insert_node(&mut self, node: N) -> usize {
+        if let Some(index) = self.indices.get(&node) {
+            return *index;
+        }
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.edges.push(Vec::new());
+        index
+    }
+}
+
+impl<N: Clone + Eq + Hash, E: Copy + Ord + Default + Add<Output = E>> Graph<N, E> {
+    /// Dijkstra's algorithm from `start`, with the edge weights as distances. `E::default()` is
+    /// used as zero, so the weights must not be negative.
+    pub fn shortest_paths(&self, start: &N) -> ShortestPaths<'_, N, E> {
+        let mut distances: Vec<Option<E>> = vec![None; self.nodes.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut queue: BinaryHeap<Reverse<(E, usize)>> = BinaryHeap::new();
+        let start_index = self.indices.get(start).copied();
+        if let Some(start_index) = start_index {
+            distances[start_index] = Some(E::default());
+            queue.push(Reverse((E::default(), start_index)));
+        }
+
+        while let Some(Reverse((distance, current))) = queue.pop() {
+            if distances[current].is_some_and(|best| best < distance) {
+                continue; // We already found a shorter way to this node.
+            }
+            for (to, weight) in self.edges[current].iter() {
+                let new_distance = distance + *weight;
+                if distances[*to].is_none_or(|best| new_distance < best) {
+                    distances[*to] = Some(new_distance);
+                    previous[*to] = Some(current);
+                    queue.push(Reverse((new_distance, *to)));
+                }
+            }
+        }
+
+        ShortestPaths { graph: self, start_index, distances, previous }
+    }
+
+    /// The length of the shortest path from `from` to `to` and the nodes on it, both ends included.
+    pub fn shortest_path(&self, from: &N, to: &N) -> Option<(E, Vec<&N>)> {
+        let paths = self.shortest_paths(from);
+        Some((paths.distance_to(to)?, paths.path_to(to)?))
+    }
+}
+
+/// The outcome of `Graph::shortest_paths`: the distance to every reachable node and the node
+/// each one was reached from.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<'a, N, E> {
+    graph: &'a Graph<N, E>,
+    start_index: Option<usize>,
+    distances: Vec<Option<E>>,
+    previous: Vec<Option<usize>>,
+}
+
+impl<'a, N: Clone + Eq + Hash, E: Copy> ShortestPaths<'a, N, E> {
+    /// The distance from the start to `node`, None when it can't be reached.
+    pub fn distance_to(&self, node: &N) -> Option<E> {
+        self.distances[*self.graph.indices.get(node)?]
+    }
+
+    /// The shortest path from the start to `node`, both included, or None when `node` can't be reached.
+    pub fn path_to(&self, node: &N) -> Option<Vec<&'a N>> {
+        let mut current = *self.graph.indices.get(node)?;
+        self.distances[current]?;
+        let mut path: Vec<&N> = vec![&self.graph.nodes[current]];
+        while Some(current) != self.start_index {
+            current = self.previous[current]?;
+            path.push(&self.graph.nodes[current]);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_graph(edges: &[(&'static str, &'static str, u64)]) -> Graph<&'static str, u64> {
+        let mut graph: Graph<&str, u64> = Graph::new();
+        for (from, to, weight) in edges {
+            graph.add_edge(*from, *to, *weight);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_add_nodes_and_edges() {
+        // Arrange
+        let mut graph = create_graph(&[("a", "b", 1), ("a", "c", 2)]);
+
+        // Act
+        let is_added = graph.add_node("d");
+        let is_added_again = graph.add_node("a");
+
+        // Assert
+        assert!(is_added);
+        assert!(!is_added_again);
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.neighbours(&"a").collect::<Vec<_>>(), vec![(&"b", &1), (&"c", &2)]);
+        assert_eq!(graph.neighbours(&"x").count(), 0);
+        assert_eq!(graph.edge(&"a", &"c"), Some(&2));
+        assert_eq!(graph.edge(&"c", &"a"), None);
+    }
+
+    #[test]
+    fn test_bfs_and_dfs() {
+        // Arrange
+        let graph = create_graph(&[("a", "b", 1), ("a", "c", 1), ("b", "d", 1), ("c", "e", 1), ("x", "a", 1)]);
+
+        // Act
+        let bfs_order = graph.bfs(&"a");
+        let dfs_order = graph.dfs(&"a");
+
+        // Assert
+        assert_eq!(bfs_order, vec![&"a", &"b", &"c", &"d", &"e"]);
+        assert_eq!(dfs_order, vec![&"a", &"b", &"d", &"c", &"e"]);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        // Arrange
+        let graph = create_graph(&[("shirt", "tie", 0), ("tie", "jacket", 0), ("pants", "shoes", 0),
+                                   ("pants", "jacket", 0), ("socks", "shoes", 0)]);
+
+        // Act
+        let actual = graph.topological_sort();
+
+        // Assert
+        assert_eq!(actual, Ok(vec![&"shirt", &"pants", &"socks", &"tie", &"shoes", &"jacket"]));
+    }
+
+    #[test]
+    fn test_topological_sort_with_cycle() {
+        // Arrange
+        let graph = create_graph(&[("a", "b", 0), ("b", "c", 0), ("c", "d", 0), ("d", "b", 0), ("d", "e", 0)]);
+
+        // Act
+        let actual = graph.topological_sort();
+
+        // Assert
+        let error = actual.unwrap_err();
+        assert_eq!(error.cycle, vec!["b", "c", "d", "b"]);
+        assert_eq!(error.to_string(), "Graph contains a cycle: \"b\" -> \"c\" -> \"d\" -> \"b\"");
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        // Arrange
+        let graph = create_graph(&[("a", "b", 0), ("b", "c", 0), ("c", "a", 0), ("c", "d", 0),
+                                   ("d", "e", 0), ("e", "d", 0), ("e", "f", 0)]);
+
+        // Act
+        let mut actual: Vec<Vec<&&str>> = graph.strongly_connected_components();
+        actual.iter_mut().for_each(|component| component.sort());
+
+        // Assert
+        assert_eq!(actual, vec![vec![&"a", &"b", &"c"], vec![&"d", &"e"], vec![&"f"]]);
+    }
+
+    #[test]
+    fn test_shortest_paths() {
+        // Arrange
+        let graph = create_graph(&[("a", "b", 7), ("a", "c", 2), ("c", "b", 3), ("b", "d", 1), ("e", "a", 1)]);
+
+        // Act
+        let paths = graph.shortest_paths(&"a");
+
+        // Assert
+        assert_eq!(paths.distance_to(&"b"), Some(5));
+        assert_eq!(paths.distance_to(&"e"), None);
+        assert_eq!(paths.path_to(&"d"), Some(vec![&"a", &"c", &"b", &"d"]));
+        assert_eq!(paths.path_to(&"a"), Some(vec![&"a"]));
+        assert_eq!(graph.shortest_path(&"a", &"d"), Some((6, vec![&"a", &"c", &"b", &"d"])));
+        assert_eq!(graph.shortest_path(&"d", &"a"), None);
+    }
+}
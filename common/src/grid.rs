@@ -1,6 +1,7 @@
 use std::str::Lines;
+use crate::{Point, SubGrid};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Grid<T> {
     data: Vec<Vec<T>>
 }
@@ -13,13 +14,127 @@ impl<T> Grid<T> where T: From<char>, // Ensures T can be constructed from a char
             .collect();
         Grid { data }
     }
+}
+
+/// Create a grid from its rows, all rows should have the same length.
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(data: Vec<Vec<T>>) -> Self {
+        Grid { data }
+    }
+}
+
+impl<T> Grid<T> where T: Clone {
+    /// Create a grid of the given size with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid { data: vec![vec![fill; width]; height] }
+    }
+
+    /// Mirror the grid in its main diagonal, so rows become columns.
+    pub fn transposed(&self) -> Self {
+        let data = (0..self.width())
+            .map(|x| self.data.iter().map(|row| row[x].clone()).collect())
+            .collect();
+        Grid { data }
+    }
+
+    /// Rotate the grid by 90 degrees clockwise.
+    pub fn rotated_clockwise(&self) -> Self {
+        self.flipped_vertically().transposed()
+    }
+
+    pub fn rotated_180(&self) -> Self {
+        self.flipped_vertically().flipped_horizontally()
+    }
 
+    /// Rotate the grid by 90 degrees counterclockwise (or 270 degrees clockwise).
+    pub fn rotated_counterclockwise(&self) -> Self {
+        self.transposed().flipped_vertically()
+    }
+
+    /// Mirror the grid left to right.
+    pub fn flipped_horizontally(&self) -> Self {
+        let data = self.data
+            .iter()
+            .map(|row| row.iter().rev().cloned().collect())
+            .collect();
+        Grid { data }
+    }
+
+    /// Mirror the grid top to bottom.
+    pub fn flipped_vertically(&self) -> Self {
+        Grid { data: self.data.iter().rev().cloned().collect() }
+    }
+}
+
+impl<T> Grid<T> {
     pub fn at(&self, x: usize, y: usize) -> Option<&T> {
         self.data
             .get(y)
             .and_then(|row| row.get(x))
     }
 
+    pub fn at_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.data
+            .get_mut(y)
+            .and_then(|row| row.get_mut(x))
+    }
+
+    /// The cell at `p`, or None when `p` lies outside the grid (including negative coordinates).
+    pub fn at_point(&self, p: Point) -> Option<&T> {
+        let (x, y) = self.to_indices(p)?;
+        self.at(x, y)
+    }
+
+    pub fn at_point_mut(&mut self, p: Point) -> Option<&mut T> {
+        let (x, y) = self.to_indices(p)?;
+        self.at_mut(x, y)
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.to_indices(p).is_some()
+    }
+
+    /// The points above, left of, right of and below `p` that lie within the grid.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.orthogonal_points().filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The (at most 8) points surrounding `p`, including diagonals, that lie within the grid.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        let neighbours: Vec<Point> = p.adjacent_points().collect();
+        neighbours.into_iter().filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// A view on the rectangle with its top left corner at (x, y), without copying any cells.
+    /// Returns None when the rectangle doesn't fit within the grid.
+    pub fn sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> Option<SubGrid<'_, T>> {
+        if x.checked_add(width)? > self.width() || y.checked_add(height)? > self.height() {
+            return None;
+        }
+        Some(SubGrid::new(self, x, y, width, height))
+    }
+
+    fn to_indices(&self, p: Point) -> Option<(usize, usize)> {
+        let x = usize::try_from(p.x).ok()?;
+        let y = usize::try_from(p.y).ok()?;
+        if x < self.width() && y < self.height() {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Set the cell at (x, y) to `value`. Returns false if (x, y) lies outside the grid.
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> bool {
+        match self.at_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false
+        }
+    }
+
     pub fn width(&self) -> usize {
         self.data.first().map_or(0, |row| row.len())
     }
@@ -27,6 +142,53 @@ impl<T> Grid<T> where T: From<char>, // Ensures T can be constructed from a char
     pub fn height(&self) -> usize {
         self.data.len() // Number of rows in the grid
     }
+
+    /// Iterate over all cells row by row, together with their position.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row
+                .iter()
+                .enumerate()
+                .map(move |(x, cell)| (Point::new(x as i64, y as i64), cell)))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        self.data.get(y).map(|row| row.as_slice())
+    }
+
+    /// The cells of column `x` from top to bottom. Since a column isn't stored contiguously,
+    /// this returns references to the cells instead of a slice.
+    pub fn column(&self, x: usize) -> Option<Vec<&T>> {
+        if x >= self.width() {
+            return None;
+        }
+        Some(self.data.iter().map(|row| &row[x]).collect())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.iter().map(|row| row.as_slice())
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Vec<&T>> {
+        (0..self.width()).map(|x| self.data.iter().map(|row| &row[x]).collect())
+    }
+
+    /// The position of the first cell (row by row) for which `predicate` holds.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// The positions of all cells (row by row) for which `predicate` holds.
+    pub fn positions(&self, predicate: impl Fn(&T) -> bool) -> Vec<Point> {
+        self.iter()
+            .filter(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -85,4 +247,306 @@ mod tests {
         assert_eq!(grid.height(), 0);
         assert_eq!(grid.at(0, 0), None);
     }
+
+    #[test]
+    fn test_new() {
+        // Arrange
+        let expected: Grid<char> = Grid::parse("...\n...\n".lines());
+
+        // Act
+        let actual: Grid<char> = Grid::new(3, 2, '.');
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_set() {
+        // Arrange
+        let mut grid: Grid<char> = Grid::parse("abc\ndef\n".lines());
+
+        // Act
+        let is_set = grid.set(1, 1, 'x');
+
+        // Assert
+        assert!(is_set);
+        assert_eq!(grid.at(1, 1), Some(&'x'));
+    }
+
+    #[test]
+    fn test_set_out_of_bounds() {
+        // Arrange
+        let mut grid: Grid<char> = Grid::parse("abc\ndef\n".lines());
+        let expected = grid.clone();
+
+        // Act
+        let is_set = grid.set(3, 0, 'x');
+
+        // Assert
+        assert!(!is_set);
+        assert_eq!(grid, expected);
+    }
+
+    #[test]
+    fn test_at_mut() {
+        // Arrange
+        let mut grid: Grid<u32> = Grid::new(2, 2, 0);
+
+        // Act
+        *grid.at_mut(0, 1).unwrap() += 5;
+
+        // Assert
+        assert_eq!(grid.at(0, 1), Some(&5));
+    }
+
+    #[test]
+    fn test_iter() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("ab\ncd\n".lines());
+        let expected = vec![
+            (Point::new(0, 0), &'a'),
+            (Point::new(1, 0), &'b'),
+            (Point::new(0, 1), &'c'),
+            (Point::new(1, 1), &'d'),
+        ];
+
+        // Act
+        let actual: Vec<(Point, &char)> = grid.iter().collect();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abc\ndef\n".lines());
+
+        // Act
+        let rows: Vec<&[char]> = grid.rows().collect();
+        let columns: Vec<Vec<&char>> = grid.columns().collect();
+
+        // Assert
+        assert_eq!(rows, vec![&['a', 'b', 'c'][..], &['d', 'e', 'f'][..]]);
+        assert_eq!(columns, vec![vec![&'a', &'d'], vec![&'b', &'e'], vec![&'c', &'f']]);
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.column(2), Some(vec![&'c', &'f']));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(3), None);
+    }
+
+    #[test]
+    fn test_find_and_positions() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("#.#\n.S.\n#..\n".lines());
+
+        // Act
+        let start = grid.find(|ch| *ch == 'S');
+        let walls = grid.positions(|ch| *ch == '#');
+
+        // Assert
+        assert_eq!(start, Some(Point::new(1, 1)));
+        assert_eq!(walls, vec![Point::new(0, 0), Point::new(2, 0), Point::new(0, 2)]);
+    }
+
+    #[test]
+    fn test_at_point() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abcd\nefgh\nijkl\n".lines());
+
+        // Act & Assert
+        assert_eq!(grid.at_point(Point::new(3, 2)), Some(&'l'));
+        assert_eq!(grid.at_point(Point::new(-1, 0)), None);
+        assert_eq!(grid.at_point(Point::new(0, -1)), None);
+        assert_eq!(grid.at_point(Point::new(4, 0)), None);
+        assert_eq!(grid.at_point(Point::new(0, 3)), None);
+    }
+
+    #[test]
+    fn test_at_point_mut() {
+        // Arrange
+        let mut grid: Grid<char> = Grid::parse("ab\ncd\n".lines());
+
+        // Act
+        *grid.at_point_mut(Point::new(1, 0)).unwrap() = 'x';
+
+        // Assert
+        assert_eq!(grid.at(1, 0), Some(&'x'));
+        assert_eq!(grid.at_point_mut(Point::new(2, 0)), None);
+    }
+
+    #[test]
+    fn test_contains() {
+        // Arrange
+        let grid: Grid<char> = Grid::new(3, 2, '.');
+
+        // Act & Assert
+        assert!(grid.contains(Point::new(0, 0)));
+        assert!(grid.contains(Point::new(2, 1)));
+        assert!(!grid.contains(Point::new(3, 1)));
+        assert!(!grid.contains(Point::new(-1, 1)));
+    }
+
+    #[test]
+    fn test_neighbours4_in_corner() {
+        // Arrange
+        let grid: Grid<char> = Grid::new(3, 3, '.');
+        let expected = vec![Point::new(1, 0), Point::new(0, 1)];
+
+        // Act
+        let actual: Vec<Point> = grid.neighbours4(Point::new(0, 0)).collect();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_neighbours8_in_corner() {
+        // Arrange
+        let grid: Grid<char> = Grid::new(3, 3, '.');
+        let expected = vec![Point::new(1, 1), Point::new(2, 1), Point::new(1, 2)];
+
+        // Act
+        let actual: Vec<Point> = grid.neighbours8(Point::new(2, 2)).collect();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_neighbours8_in_center() {
+        // Arrange
+        let grid: Grid<char> = Grid::new(3, 3, '.');
+
+        // Act
+        let actual = grid.neighbours8(Point::new(1, 1)).count();
+
+        // Assert
+        assert_eq!(actual, 8);
+    }
+
+    #[test]
+    fn test_transposed() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abc\ndef\n".lines());
+        let expected: Grid<char> = Grid::parse("ad\nbe\ncf\n".lines());
+
+        // Act
+        let actual = grid.transposed();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_rotated_clockwise() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abc\ndef\n".lines());
+        let expected: Grid<char> = Grid::parse("da\neb\nfc\n".lines());
+
+        // Act
+        let actual = grid.rotated_clockwise();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_rotated_180() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abc\ndef\n".lines());
+        let expected: Grid<char> = Grid::parse("fed\ncba\n".lines());
+
+        // Act
+        let actual = grid.rotated_180();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_rotated_counterclockwise() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abc\ndef\n".lines());
+        let expected: Grid<char> = Grid::parse("cf\nbe\nad\n".lines());
+
+        // Act
+        let actual = grid.rotated_counterclockwise();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_four_rotations_give_original() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abc\ndef\n".lines());
+
+        // Act
+        let actual = grid.rotated_clockwise().rotated_clockwise().rotated_clockwise().rotated_clockwise();
+
+        // Assert
+        assert_eq!(actual, grid);
+    }
+
+    #[test]
+    fn test_flipped_horizontally() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abc\ndef\n".lines());
+        let expected: Grid<char> = Grid::parse("cba\nfed\n".lines());
+
+        // Act
+        let actual = grid.flipped_horizontally();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_flipped_vertically() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abc\ndef\n".lines());
+        let expected: Grid<char> = Grid::parse("def\nabc\n".lines());
+
+        // Act
+        let actual = grid.flipped_vertically();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_transposed_empty_grid() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("".lines());
+
+        // Act
+        let actual = grid.transposed();
+
+        // Assert
+        assert_eq!(actual.width(), 0);
+        assert_eq!(actual.height(), 0);
+    }
+
+    #[test]
+    fn test_sub_grid_out_of_bounds() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abcd\nefgh\nijkl\n".lines());
+
+        // Act
+        let actual = grid.sub_grid(2, 1, 3, 2);
+
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    #[test]
+    fn test_sub_grid_overflowing_bounds() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abcd\nefgh\nijkl\n".lines());
+
+        // Act & Assert
+        assert!(grid.sub_grid(usize::MAX, 0, 2, 1).is_none());
+        assert!(grid.sub_grid(0, 1, 1, usize::MAX).is_none());
+    }
 }
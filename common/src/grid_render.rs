@@ -0,0 +1,110 @@
+use crate::{Grid, Point};
+use std::collections::HashSet;
+use std::fmt;
+
+/// Print the grid the way it was parsed: one line per row, no separators between cells.
+impl<T> fmt::Display for Grid<T> where T: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Render the grid with `format_cell` deciding how each cell is shown.
+    pub fn render(&self, format_cell: impl Fn(Point, &T) -> String) -> String {
+        let lines: Vec<String> = self.rows()
+            .enumerate()
+            .map(|(y, row)| row
+                .iter()
+                .enumerate()
+                .map(|(x, cell)| format_cell(Point::new(x as i64, y as i64), cell))
+                .collect())
+            .collect();
+        lines.join("\n")
+    }
+
+    /// Render the grid like Display does, except that the cells at `highlighted` are
+    /// shown through `highlight`, for example to give them a color in the terminal.
+    pub fn render_highlighted(&self, highlighted: impl IntoIterator<Item = Point>,
+                              highlight: impl Fn(&T) -> String) -> String
+    where
+        T: fmt::Display,
+    {
+        let highlighted: HashSet<Point> = highlighted.into_iter().collect();
+        self.render(|p, cell| if highlighted.contains(&p) { highlight(cell) } else { cell.to_string() })
+    }
+}
+
+/// Wrap text in the ANSI escape codes for bold red, to highlight it in the terminal.
+pub fn ansi_highlight(text: impl fmt::Display) -> String {
+    format!("\x1b[1;31m{}\x1b[0m", text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        // Arrange
+        let input = "abcd\nefgh\nijkl";
+        let grid: Grid<char> = Grid::parse(input.lines());
+
+        // Act
+        let actual = grid.to_string();
+
+        // Assert
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn test_display_empty_grid() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("".lines());
+
+        // Act
+        let actual = grid.to_string();
+
+        // Assert
+        assert_eq!(actual, "");
+    }
+
+    #[test]
+    fn test_render() {
+        // Arrange
+        let grid: Grid<u32> = Grid::new(3, 2, 7);
+        let expected = "7.7\n.7.";
+
+        // Act
+        let actual = grid.render(|p, cell| if (p.x + p.y) % 2 == 0 { cell.to_string() } else { ".".to_string() });
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_render_highlighted() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("ab\ncd".lines());
+        let expected = "a[b]\n[c]d";
+
+        // Act
+        let actual = grid.render_highlighted([Point::new(1, 0), Point::new(0, 1)], |ch| format!("[{}]", ch));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_ansi_highlight() {
+        assert_eq!(ansi_highlight('x'), "\x1b[1;31mx\x1b[0m");
+    }
+}
@@ -1,6 +1,51 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
 use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::str::Lines;
 
+/// Environment variable that, when set, points to the directory containing the input files.
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+
+/// Everything that can go wrong while getting hold of the puzzle input.
+#[derive(Debug)]
+pub enum InputError {
+    Read { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+    CurrentDir(io::Error),
+    WorkspaceRootNotFound(PathBuf),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Read { path, source } =>
+                write!(f, "Could not read input file '{}': {}", path.display(), source),
+            InputError::Stdin(source) =>
+                write!(f, "Could not read input from stdin: {}", source),
+            InputError::CurrentDir(source) =>
+                write!(f, "Could not determine the current directory: {}", source),
+            InputError::WorkspaceRootNotFound(start) =>
+                write!(f, "Could not find the workspace root from '{}', set {} to the input directory",
+                       start.display(), INPUT_DIR_VARIABLE),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Read { source, .. } => Some(source),
+            InputError::Stdin(source) => Some(source),
+            InputError::CurrentDir(source) => Some(source),
+            InputError::WorkspaceRootNotFound(_) => None,
+        }
+    }
+}
+
 /// Because we need something to be the owner of the strings that is read from the file,
 /// we create a struct that will hold the string and offer a function to create an iterator on it.
 pub struct InputReader {
@@ -8,13 +53,107 @@ pub struct InputReader {
 }
 
 impl InputReader {
-    pub fn new(day_nr: u8) -> Self {
-        let path = format!("./input/{:02}.txt", day_nr);
-        let input = fs::read_to_string(&path).unwrap();
-        InputReader { input }
+    /// Read the input of the given day, `NN.txt` in the input directory.
+    /// The input directory is taken from `AOC_INPUT_DIR` if set, otherwise it is the
+    /// `input` directory in the root of the workspace the current directory is in.
+    pub fn new(day_nr: u8) -> Result<Self, InputError> {
+        let path = input_dir()?.join(format!("{:02}.txt", day_nr));
+        Self::from_path(path)
+    }
+
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, InputError> {
+        let path = path.as_ref();
+        let input = fs::read_to_string(path)
+            .map_err(|source| InputError::Read { path: path.to_path_buf(), source })?;
+        Ok(InputReader { input })
+    }
+
+    pub fn from_stdin() -> Result<Self, InputError> {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(InputError::Stdin)?;
+        Ok(InputReader { input })
     }
 
-    pub fn lines(&self) -> Lines {
+    pub fn lines(&self) -> Lines<'_> {
         self.input.lines()
     }
 }
+
+fn input_dir() -> Result<PathBuf, InputError> {
+    if let Some(dir) = env::var_os(INPUT_DIR_VARIABLE) {
+        return Ok(PathBuf::from(dir));
+    }
+
+    let current_dir = env::current_dir().map_err(InputError::CurrentDir)?;
+    Ok(find_workspace_root(&current_dir)?.join("input"))
+}
+
+/// Walk up from `start` until we find the directory with the Cargo.toml defining the workspace.
+pub fn find_workspace_root(start: &Path) -> Result<PathBuf, InputError> {
+    start
+        .ancestors()
+        .find(|dir| is_workspace_root(dir))
+        .map(Path::to_path_buf)
+        .ok_or_else(|| InputError::WorkspaceRootNotFound(start.to_path_buf()))
+}
+
+fn is_workspace_root(dir: &Path) -> bool {
+    fs::read_to_string(dir.join("Cargo.toml"))
+        .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_workspace_root_from_crate_dir() {
+        // Arrange
+        let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let expected = crate_dir.parent().unwrap().to_path_buf();
+
+        // Act
+        let actual = find_workspace_root(crate_dir).unwrap();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_find_workspace_root_outside_workspace() {
+        // Arrange
+        let start = Path::new("/");
+
+        // Act
+        let actual = find_workspace_root(start);
+
+        // Assert
+        assert!(matches!(actual, Err(InputError::WorkspaceRootNotFound(_))));
+    }
+
+    #[test]
+    fn test_from_path_missing_file() {
+        // Arrange
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("does-not-exist.txt");
+
+        // Act
+        let error = InputReader::from_path(&path).err().unwrap();
+
+        // Assert
+        assert!(error.to_string().contains("does-not-exist.txt"));
+    }
+
+    #[test]
+    fn test_from_path() {
+        // Arrange
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+
+        // Act
+        let input_reader = InputReader::from_path(path).unwrap();
+
+        // Assert
+        assert_eq!(input_reader.lines().next(), Some("[package]"));
+    }
+}
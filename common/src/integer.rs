@@ -0,0 +1,109 @@
+use num_bigint::BigInt;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Returned when the result of an operation doesn't fit in the integer type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverflowError {
+    pub operation: &'static str,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Overflow in {}", self.operation)
+    }
+}
+
+impl Error for OverflowError {}
+
+/// The integer types solutions can be generic over: the primitive integers and `BigInt`.
+/// The `try_` operations report overflow instead of panicking or wrapping, they never fail for `BigInt`.
+pub trait Integer:
+    Clone + Ord + Default + fmt::Debug + From<u8>
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    fn try_add(&self, other: &Self) -> Result<Self, OverflowError>;
+    fn try_sub(&self, other: &Self) -> Result<Self, OverflowError>;
+    fn try_mul(&self, other: &Self) -> Result<Self, OverflowError>;
+
+    /// The largest integer whose square is at most `self`. Panics for negative numbers.
+    fn integer_sqrt(&self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn try_add(&self, other: &Self) -> Result<Self, OverflowError> {
+                    self.checked_add(*other).ok_or(OverflowError { operation: "addition" })
+                }
+
+                fn try_sub(&self, other: &Self) -> Result<Self, OverflowError> {
+                    self.checked_sub(*other).ok_or(OverflowError { operation: "subtraction" })
+                }
+
+                fn try_mul(&self, other: &Self) -> Result<Self, OverflowError> {
+                    self.checked_mul(*other).ok_or(OverflowError { operation: "multiplication" })
+                }
+
+                fn integer_sqrt(&self) -> Self {
+                    self.isqrt()
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+
+impl Integer for BigInt {
+    fn try_add(&self, other: &Self) -> Result<Self, OverflowError> {
+        Ok(self + other)
+    }
+
+    fn try_sub(&self, other: &Self) -> Result<Self, OverflowError> {
+        Ok(self - other)
+    }
+
+    fn try_mul(&self, other: &Self) -> Result<Self, OverflowError> {
+        Ok(self * other)
+    }
+
+    fn integer_sqrt(&self) -> Self {
+        self.sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_operations() {
+        assert_eq!(5i64.try_add(&3), Ok(8));
+        assert_eq!(i64::MAX.try_add(&1), Err(OverflowError { operation: "addition" }));
+        assert_eq!(3u128.try_sub(&5), Err(OverflowError { operation: "subtraction" }));
+        assert_eq!((u128::MAX / 2).try_mul(&2), Ok(u128::MAX - 1));
+        assert_eq!((u128::MAX / 2).try_mul(&3), Err(OverflowError { operation: "multiplication" }));
+    }
+
+    #[test]
+    fn test_big_int_never_overflows() {
+        // Arrange
+        let max: BigInt = BigInt::from(u128::MAX);
+
+        // Act
+        let actual = max.try_mul(&max).unwrap();
+
+        // Assert
+        assert_eq!(actual.integer_sqrt(), max);
+    }
+
+    #[test]
+    fn test_integer_sqrt() {
+        assert_eq!(15u64.integer_sqrt(), 3);
+        assert_eq!(16i64.integer_sqrt(), 4);
+        assert_eq!(u128::MAX.integer_sqrt(), u64::MAX as u128);
+    }
+}
@@ -1,14 +1,47 @@
 mod input_reader;
-pub use input_reader::InputReader;
+pub use input_reader::{find_workspace_root, InputError, InputReader, INPUT_DIR_VARIABLE};
 
 mod grid;
 pub use grid::Grid;
 
+mod sub_grid;
+pub use sub_grid::SubGrid;
+
+mod grid_render;
+pub use grid_render::ansi_highlight;
+
 mod point;
 pub use point::Point;
 
+mod regions;
+pub use regions::{connected_components, flood_fill, BoundingBox, Components, Connectivity, Region};
+
+mod graph;
+pub use graph::{CycleError, Graph, ShortestPaths};
+
+mod path_finding;
+pub use path_finding::{a_star, bfs, dijkstra, SearchResult};
+
 mod string_utils;
 pub use string_utils::*;
 
+mod integer;
+pub use integer::{Integer, OverflowError};
+
 mod range;
-pub use range::Range;
+pub use range::{Range, RangeIter};
+
+mod cuboid;
+pub use cuboid::Cuboid;
+
+mod range_set;
+pub use range_set::RangeSet;
+
+mod number_theory;
+pub use number_theory::{crt, extended_gcd, gcd, gcd_all, lcm, lcm_all, mod_inverse, mod_pow, CrtError};
+
+mod answer;
+pub use answer::Answer;
+
+mod solution;
+pub use solution::Solution;
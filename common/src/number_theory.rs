@@ -0,0 +1,209 @@
+use std::error::Error;
+use std::fmt;
+
+/// Everything that can go wrong while solving a system of congruences with `crt`.
+#[derive(Debug, PartialEq)]
+pub enum CrtError {
+    /// A modulus is not positive.
+    InvalidModulus { index: usize, modulus: i64 },
+    /// The congruence at this index contradicts the ones before it.
+    NoSolution { index: usize },
+    /// The combined modulus doesn't fit in an i64.
+    Overflow,
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::InvalidModulus { index, modulus } =>
+                write!(f, "Congruence {} has modulus {}, it should be positive", index, modulus),
+            CrtError::NoSolution { index } =>
+                write!(f, "Congruence {} contradicts the ones before it", index),
+            CrtError::Overflow =>
+                write!(f, "The combined modulus is too large"),
+        }
+    }
+}
+
+impl Error for CrtError {}
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// The least common multiple, None when it doesn't fit in a u64.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The greatest common divisor of all numbers, 0 when there are none.
+pub fn gcd_all(numbers: &[u64]) -> u64 {
+    numbers.iter().fold(0, |acc, n| gcd(acc, *n))
+}
+
+/// The least common multiple of all numbers, 1 when there are none and None when it doesn't fit in a u64.
+pub fn lcm_all(numbers: &[u64]) -> Option<u64> {
+    numbers.iter().try_fold(1, |acc, n| lcm(acc, *n))
+}
+
+/// Returns `(g, x, y)` with `g` the greatest common divisor of `a` and `b`, and `a * x + b * y = g`.
+/// None when the result doesn't fit in an i64, which only happens when `i64::MIN` is involved,
+/// like the gcd of `i64::MIN` and 0.
+pub fn extended_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    let (mut old_r, mut r) = (a as i128, b as i128);
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+    }
+    Some((i64::try_from(old_r).ok()?, i64::try_from(old_x).ok()?, i64::try_from(old_y).ok()?))
+}
+
+/// The number `x` in `0..modulus` with `a * x = 1 (mod modulus)`, None when `a` and `modulus`
+/// are not coprime.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus)?;
+    if g != 1 {
+        return None;
+    }
+    Some(x.rem_euclid(modulus))
+}
+
+/// `base` to the power `exponent`, modulo `modulus`, without overflowing along the way.
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "The modulus should be positive");
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result: u128 = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+/// Solve the system `x = residue (mod modulus)` for all `(residue, modulus)` pairs with the
+/// Chinese Remainder Theorem. The moduli don't have to be coprime.
+/// Returns `(x, m)`: the solutions are all numbers equal to `x` modulo `m`, with `x` in `0..m`.
+pub fn crt(congruences: &[(i64, i64)]) -> Result<(i64, i64), CrtError> {
+    let (mut x, mut m): (i128, i128) = (0, 1);
+    for (index, (residue, modulus)) in congruences.iter().enumerate() {
+        if *modulus <= 0 {
+            return Err(CrtError::InvalidModulus { index, modulus: *modulus });
+        }
+        let (residue, modulus) = ((*residue as i128).rem_euclid(*modulus as i128), *modulus as i128);
+
+        // Find k with x + m * k = residue (mod modulus), that is m * k = residue - x (mod modulus).
+        let (g, m_inverse, _) = extended_gcd(m as i64, modulus as i64).ok_or(CrtError::Overflow)?;
+        let (g, m_inverse) = (g as i128, m_inverse as i128);
+        let difference = residue - x;
+        if difference % g != 0 {
+            return Err(CrtError::NoSolution { index });
+        }
+        let reduced_modulus = modulus / g;
+        let k = (difference / g % reduced_modulus * m_inverse).rem_euclid(reduced_modulus);
+
+        let new_m = m * reduced_modulus;
+        if new_m > i64::MAX as i128 {
+            return Err(CrtError::Overflow);
+        }
+        x = (x + m * k).rem_euclid(new_m);
+        m = new_m;
+    }
+
+    Ok((x as i64, m as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(u64::MAX, 2), None);
+    }
+
+    #[test]
+    fn test_gcd_all_and_lcm_all() {
+        assert_eq!(gcd_all(&[12, 18, 27]), 3);
+        assert_eq!(gcd_all(&[]), 0);
+        assert_eq!(lcm_all(&[2, 3, 4]), Some(12));
+        assert_eq!(lcm_all(&[]), Some(1));
+        assert_eq!(lcm_all(&[1 << 40, 3, 1 << 30, 5 << 30]), Some(15 << 40));
+        assert_eq!(lcm_all(&[u64::MAX, u64::MAX - 1]), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        // Arrange
+        let (a, b) = (240, -46);
+
+        // Act
+        let (g, x, y) = extended_gcd(a, b).unwrap();
+
+        // Assert
+        assert_eq!(g, 2);
+        assert_eq!(a * x + b * y, g);
+    }
+
+    #[test]
+    fn test_extended_gcd_overflow() {
+        assert_eq!(extended_gcd(i64::MIN, 0), None);
+        assert_eq!(extended_gcd(0, i64::MIN), None);
+        assert_eq!(extended_gcd(i64::MIN, 3).map(|(g, _, _)| g), Some(1));
+        assert_eq!(extended_gcd(i64::MAX, 0), Some((i64::MAX, 1, 0)));
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(3, 0), None);
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(2, 10, 1_000), 24);
+        assert_eq!(mod_pow(u64::MAX, u64::MAX, u64::MAX - 58), mod_pow(58, u64::MAX, u64::MAX - 58));
+        assert_eq!(mod_pow(5, 0, 1), 0);
+    }
+
+    #[test]
+    fn test_crt_coprime() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+    }
+
+    #[test]
+    fn test_crt_not_coprime() {
+        assert_eq!(crt(&[(2, 6), (8, 10)]), Ok((8, 30)));
+        assert_eq!(crt(&[(-1, 4), (1, 6)]), Ok((7, 12)));
+        assert_eq!(crt(&[]), Ok((0, 1)));
+    }
+
+    #[test]
+    fn test_crt_errors() {
+        assert_eq!(crt(&[(1, 6), (2, 4)]), Err(CrtError::NoSolution { index: 1 }));
+        assert_eq!(crt(&[(1, 6), (2, 0)]), Err(CrtError::InvalidModulus { index: 1, modulus: 0 }));
+        assert_eq!(crt(&[(0, 1 << 40), (1, (1 << 40) - 1)]), Err(CrtError::Overflow));
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{Grid, Point};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+/// The outcome of searching a grid from a start point: the distance to every reachable cell and
+/// the cell each one was reached from, so the shortest path to any cell can be reconstructed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    start: Point,
+    distances: Grid<Option<u64>>,
+    previous: Grid<Option<Point>>,
+}
+
+impl SearchResult {
+    fn new(width: usize, height: usize, start: Point) -> Self {
+        let mut distances = Grid::new(width, height, None);
+        if let Some(distance) = distances.at_point_mut(start) {
+            *distance = Some(0);
+        }
+        SearchResult { start, distances, previous: Grid::new(width, height, None) }
+    }
+
+    /// The distance from the start to every cell, None for cells that can't be reached.
+    pub fn distances(&self) -> &Grid<Option<u64>> {
+        &self.distances
+    }
+
+    pub fn distance_to(&self, p: Point) -> Option<u64> {
+        self.distances.at_point(p).copied().flatten()
+    }
+
+    /// The shortest path from the start to `p`, both included, or None when `p` can't be reached.
+    pub fn path_to(&self, p: Point) -> Option<Vec<Point>> {
+        self.distance_to(p)?;
+        let mut path = vec![p];
+        let mut current = p;
+        while current != self.start {
+            current = self.previous.at_point(current).copied().flatten()?;
+            path.push(current);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    fn record(&mut self, p: Point, distance: u64, previous: Point) {
+        *self.distances.at_point_mut(p).unwrap() = Some(distance);
+        *self.previous.at_point_mut(p).unwrap() = Some(previous);
+    }
+}
+
+/// Breadth-first search from `start`, moving horizontally and vertically, where every step
+/// costs 1. `is_passable` tells whether a cell can be entered.
+pub fn bfs<T>(grid: &Grid<T>, start: Point, is_passable: impl Fn(Point, &T) -> bool) -> SearchResult {
+    let mut result = SearchResult::new(grid.width(), grid.height(), start);
+    let mut queue: VecDeque<Point> = VecDeque::new();
+    if grid.contains(start) {
+        queue.push_back(start);
+    }
+
+    while let Some(current) = queue.pop_front() {
+        let distance = result.distance_to(current).unwrap();
+        for neighbour in grid.neighbours4(current) {
+            if result.distance_to(neighbour).is_none() && is_passable(neighbour, grid.at_point(neighbour).unwrap()) {
+                result.record(neighbour, distance + 1, current);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's algorithm from `start`, moving horizontally and vertically. `cost` returns the
+/// cost of entering a cell, or None when the cell can't be entered.
+pub fn dijkstra<T>(grid: &Grid<T>, start: Point, cost: impl Fn(Point, &T) -> Option<u64>) -> SearchResult {
+    let mut result = SearchResult::new(grid.width(), grid.height(), start);
+    let mut queue: BinaryHeap<Reverse<(u64, Point)>> = BinaryHeap::new();
+    if grid.contains(start) {
+        queue.push(Reverse((0, start)));
+    }
+
+    while let Some(Reverse((distance, current))) = queue.pop() {
+        if result.distance_to(current).is_some_and(|best| best < distance) {
+            continue; // We already found a shorter way to this cell.
+        }
+        for neighbour in grid.neighbours4(current) {
+            let Some(step_cost) = cost(neighbour, grid.at_point(neighbour).unwrap()) else {
+                continue;
+            };
+            let new_distance = distance + step_cost;
+            if result.distance_to(neighbour).is_none_or(|best| new_distance < best) {
+                result.record(neighbour, new_distance, current);
+                queue.push(Reverse((new_distance, neighbour)));
+            }
+        }
+    }
+
+    result
+}
+
+/// A* search from `start` to `goal`, moving horizontally and vertically, with the Manhattan
+/// distance to `goal` as heuristic. `cost` works like it does for `dijkstra`. The heuristic
+/// only guarantees the shortest path when entering a cell costs at least 1.
+/// Returns the total cost and the path, both ends included, or None when `goal` can't be reached.
+pub fn a_star<T>(grid: &Grid<T>, start: Point, goal: Point,
+                 cost: impl Fn(Point, &T) -> Option<u64>) -> Option<(u64, Vec<Point>)> {
+    let mut result = SearchResult::new(grid.width(), grid.height(), start);
+    let mut queue: BinaryHeap<Reverse<(u64, Point)>> = BinaryHeap::new();
+    if grid.contains(start) {
+        queue.push(Reverse((start.manhattan_distance(&goal), start)));
+    }
+
+    while let Some(Reverse((_, current))) = queue.pop() {
+        let distance = result.distance_to(current).unwrap();
+        if current == goal {
+            return Some((distance, result.path_to(goal).unwrap()));
+        }
+        for neighbour in grid.neighbours4(current) {
+            let Some(step_cost) = cost(neighbour, grid.at_point(neighbour).unwrap()) else {
+                continue;
+            };
+            let new_distance = distance + step_cost;
+            if result.distance_to(neighbour).is_none_or(|best| new_distance < best) {
+                result.record(neighbour, new_distance, current);
+                queue.push(Reverse((new_distance + neighbour.manhattan_distance(&goal), neighbour)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static MAZE: &str = r#"S.#.
+.##.
+...E"#;
+
+    fn parse_maze() -> (Grid<char>, Point, Point) {
+        let grid: Grid<char> = Grid::parse(MAZE.lines());
+        let start = grid.find(|ch| *ch == 'S').unwrap();
+        let end = grid.find(|ch| *ch == 'E').unwrap();
+        (grid, start, end)
+    }
+
+    #[test]
+    fn test_bfs() {
+        // Arrange
+        let (grid, start, end) = parse_maze();
+
+        // Act
+        let result = bfs(&grid, start, |_, ch| *ch != '#');
+
+        // Assert
+        assert_eq!(result.distance_to(end), Some(5));
+        assert_eq!(result.distance_to(Point::new(1, 0)), Some(1));
+        assert_eq!(result.distance_to(Point::new(2, 0)), None);
+        assert_eq!(result.path_to(end), Some(vec![
+            Point::new(0, 0), Point::new(0, 1), Point::new(0, 2),
+            Point::new(1, 2), Point::new(2, 2), Point::new(3, 2)
+        ]));
+    }
+
+    #[test]
+    fn test_bfs_distance_map() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("..\n#.".lines());
+        let expected: Grid<Option<u64>> = Grid::from(vec![vec![Some(0), Some(1)], vec![None, Some(2)]]);
+
+        // Act
+        let result = bfs(&grid, Point::new(0, 0), |_, ch| *ch != '#');
+
+        // Assert
+        assert_eq!(*result.distances(), expected);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // Arrange
+        let grid: Grid<u32> = Grid::from(vec![
+            vec![1, 9, 1],
+            vec![1, 9, 1],
+            vec![1, 1, 1],
+        ]);
+
+        // Act
+        let result = dijkstra(&grid, Point::new(0, 0), |_, cost| Some(*cost as u64));
+
+        // Assert
+        assert_eq!(result.distance_to(Point::new(2, 0)), Some(6));
+        assert_eq!(result.path_to(Point::new(2, 0)).unwrap().len(), 7);
+    }
+
+    #[test]
+    fn test_dijkstra_impassable() {
+        // Arrange
+        let (grid, start, end) = parse_maze();
+
+        // Act
+        let result = dijkstra(&grid, start, |_, ch| if *ch == '#' { None } else { Some(2) });
+
+        // Assert
+        assert_eq!(result.distance_to(end), Some(10));
+        assert_eq!(result.path_to(Point::new(3, 0)), Some(vec![
+            Point::new(0, 0), Point::new(0, 1), Point::new(0, 2), Point::new(1, 2),
+            Point::new(2, 2), Point::new(3, 2), Point::new(3, 1), Point::new(3, 0)
+        ]));
+    }
+
+    #[test]
+    fn test_a_star() {
+        // Arrange
+        let (grid, start, end) = parse_maze();
+
+        // Act
+        let actual = a_star(&grid, start, end, |_, ch| if *ch == '#' { None } else { Some(1) });
+
+        // Assert
+        let (distance, path) = actual.unwrap();
+        assert_eq!(distance, 5);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&end));
+        assert_eq!(path.len(), 6);
+    }
+
+    #[test]
+    fn test_a_star_unreachable() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("S#E".lines());
+
+        // Act
+        let actual = a_star(&grid, Point::new(0, 0), Point::new(2, 0), |_, ch| if *ch == '#' { None } else { Some(1) });
+
+        // Assert
+        assert_eq!(actual, None);
+    }
+}
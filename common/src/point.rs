@@ -1,4 +1,4 @@
-#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash, PartialOrd, Ord)]
 pub struct Point {
     pub x: i64,
     pub y: i64,
@@ -24,12 +24,20 @@ impl Point {
     }
 
     /// Returns an iterator over the surrounding points of this point.
-    pub fn adjacent_points(&self) -> AdjacentPoints {
+    pub fn adjacent_points(&self) -> AdjacentPoints<'_> {
         AdjacentPoints {
             center: self,
             index: 0,
         }
     }
+
+    /// Returns an iterator over the points above, left of, right of and below this point.
+    pub fn orthogonal_points(&self) -> impl Iterator<Item = Point> {
+        let center = *self;
+        [(0, -1), (-1, 0), (1, 0), (0, 1)]
+            .into_iter()
+            .map(move |(dx, dy)| center.translate(dx, dy))
+    }
 }
 
 /// Custom iterator to iterate over the adjacent points of a Point.
@@ -109,4 +117,17 @@ mod tests {
         // Assert
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_orthogonal_points() {
+        // Arrange
+        let point = Point::new(3, 4);
+        let expected = vec![Point::new(3, 3), Point::new(2, 4), Point::new(4, 4), Point::new(3, 5)];
+
+        // Act
+        let actual: Vec<Point> = point.orthogonal_points().collect();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
 }
@@ -1,9 +1,14 @@
+use crate::{Integer, OverflowError};
 use std::fmt;
+use std::ops::Add;
 
-#[derive(Clone, Copy, PartialEq)]
+/// The numbers from `start` up to, but not including, `start + length`. Only needs `Clone`, so
+/// besides the primitive integers it also works for arbitrary-precision integers like `BigInt`.
+/// The end always fits in `T`: the constructors check it, so `end()` can't overflow.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Range<T>
 where
-    T: PartialOrd + Copy,
+    T: PartialOrd + Clone,
 {
     start: T,
     length: T,
@@ -11,35 +16,59 @@ where
 
 impl<T> fmt::Debug for Range<T>
 where
-    T: PartialOrd + Copy + std::ops::Add<Output = T> + fmt::Debug,
+    T: PartialOrd + Clone + Add<Output = T> + fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{:?}..{:?})", self.start, self.start + self.length)
+        write!(f, "[{:?}..{:?})", self.start, self.start.clone() + self.length.clone())
     }
 }
 
-impl<T> Range<T>
-where
-    T: PartialOrd + Copy + std::ops::Add<Output = T> + std::ops::Sub<Output = T> + Default,
-{
+impl<T: Integer> Range<T> {
+    /// Create the range. Panics when its end doesn't fit in `T`, use `try_new` for ranges that
+    /// come from input.
     pub fn new(start: T, length: T) -> Self {
-        Range { start, length }
+        Range::try_new(start, length)
+            .unwrap_or_else(|error| panic!("The end of the range doesn't fit: {}", error))
+    }
+
+    /// Create the range, failing when its end doesn't fit in `T`.
+    pub fn try_new(start: T, length: T) -> Result<Self, OverflowError> {
+        start.try_add(&length)?;
+        Ok(Range { start, length })
+    }
+
+    /// Create the range from `start` up to, but not including, `end`.
+    /// The range is empty when `end` is not after `start`. Panics when the length doesn't fit in `T`.
+    pub fn from_start_end(start: T, end: T) -> Self {
+        Range::try_from_start_end(start, end)
+            .unwrap_or_else(|error| panic!("The length of the range doesn't fit: {}", error))
+    }
+
+    /// Like `from_start_end`, failing when the length doesn't fit in `T`, like for
+    /// `i64::MIN..i64::MAX`.
+    pub fn try_from_start_end(start: T, end: T) -> Result<Self, OverflowError> {
+        let length = if end > start { end.try_sub(&start)? } else { T::default() };
+        Ok(Range { start, length })
     }
 
     pub fn start(&self) -> T {
-        self.start
+        self.start.clone()
     }
 
     pub fn length(&self) -> T {
-        self.length
+        self.length.clone()
     }
 
     pub fn end(&self) -> T {
-        self.start + self.length
+        self.start() + self.length()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length <= T::default()
     }
 
     pub fn contains(&self, n: T) -> bool {
-        n >= self.start && n < self.start + self.length
+        n >= self.start && n < self.end()
     }
 
     /// Check if this range overlaps with another range.
@@ -47,8 +76,30 @@ where
         self.start < other.end() && other.start < self.end()
     }
 
-    pub fn shifted(&self, delta: T) -> Self{
-        Range { start: self.start + delta, length: self.length }
+    /// The part of this range that is also in `other`, None when they don't overlap.
+    pub fn intersection(&self, other: &Range<T>) -> Option<Range<T>> {
+        // An empty range still 'overlaps' a range around it, but has nothing in common with it.
+        if self.is_empty() || other.is_empty() || !self.overlaps(other) {
+            return None;
+        }
+        let start = if self.start > other.start { self.start() } else { other.start() };
+        let end = if self.end() < other.end() { self.end() } else { other.end() };
+        Some(Range::from_start_end(start, end))
+    }
+
+    /// Panics when the shifted range doesn't fit in `T`, see `checked_shifted`.
+    pub fn shifted(&self, delta: T) -> Self {
+        self.checked_shifted(&delta)
+            .unwrap_or_else(|error| panic!("The shifted range doesn't fit: {}", error))
+    }
+
+    pub fn checked_shifted(&self, delta: &T) -> Result<Self, OverflowError> {
+        Range::try_new(self.start.try_add(delta)?, self.length())
+    }
+
+    /// Iterate over all numbers in the range.
+    pub fn iter(&self) -> RangeIter<T> {
+        RangeIter { next: self.start(), end: self.end() }
     }
 
     /// Split the range `self` into non-overlapping parts where the union of the parts
@@ -59,25 +110,25 @@ where
 
         // No overlap, return just the `self` range as it is
         if !self.overlaps(other) {
-            result.push(*self);
+            result.push(self.clone());
             return result;
         }
 
         // Calculate the overlap between `self` and `other`
-        let overlap_start = if self.start > other.start { self.start } else { other.start };
+        let overlap_start = if self.start > other.start { self.start() } else { other.start() };
         let overlap_end = if self.end() < other.end() { self.end() } else { other.end() };
 
         // Add the non-overlapping part of `self` before the overlap (if any)
         if self.start < overlap_start {
-            result.push(Range::new(self.start, overlap_start - self.start));
+            result.push(Range::new(self.start(), overlap_start.clone() - self.start()));
         }
 
         // Add the overlapping part
-        result.push(Range::new(overlap_start, overlap_end - overlap_start));
+        result.push(Range::new(overlap_start.clone(), overlap_end.clone() - overlap_start));
 
         // Add the non-overlapping part of `self` after the overlap (if any)
         if overlap_end < self.end() {
-            result.push(Range::new(overlap_end, self.end() - overlap_end));
+            result.push(Range::new(overlap_end.clone(), self.end() - overlap_end));
         }
 
         result
@@ -91,7 +142,7 @@ where
         let mut result = Vec::new();
 
         // Start with the full `self` range
-        let mut current_range = *self;
+        let mut current_range = self.clone();
 
         for other in others {
             // If there's no overlap, continue to the next range
@@ -101,9 +152,9 @@ where
 
             // Calculate the overlap
             let overlap_start = if current_range.start > other.start {
-                current_range.start
+                current_range.start()
             } else {
-                other.start
+                other.start()
             };
             let overlap_end = if current_range.end() < other.end() {
                 current_range.end()
@@ -113,14 +164,14 @@ where
 
             // Add the non-overlapping part before the overlap (if any)
             if current_range.start < overlap_start {
-                result.push(Range::new(current_range.start, overlap_start - current_range.start));
+                result.push(Range::new(current_range.start(), overlap_start.clone() - current_range.start()));
             }
 
             // Add the overlapping part
-            result.push(Range::new(overlap_start, overlap_end - overlap_start));
+            result.push(Range::new(overlap_start.clone(), overlap_end.clone() - overlap_start));
 
             // Update the current range to the remaining part after the overlap
-            current_range = Range::new(overlap_end, current_range.end() - overlap_end);
+            current_range = Range::new(overlap_end.clone(), current_range.end() - overlap_end);
         }
 
         // Add the remaining part of `self` after processing all ranges
@@ -132,9 +183,78 @@ where
     }
 }
 
+impl<T: Integer> From<std::ops::Range<T>> for Range<T> {
+    fn from(range: std::ops::Range<T>) -> Self {
+        Range::from_start_end(range.start, range.end)
+    }
+}
+
+impl<T: Integer> From<Range<T>> for std::ops::Range<T> {
+    fn from(range: Range<T>) -> Self {
+        range.start()..range.end()
+    }
+}
+
+/// Fails when the range ends at the largest value of `T`, like `0..=u8::MAX`, since the end of the
+/// `Range` is one past that.
+impl<T: Integer> TryFrom<std::ops::RangeInclusive<T>> for Range<T> {
+    type Error = OverflowError;
+
+    fn try_from(range: std::ops::RangeInclusive<T>) -> Result<Self, Self::Error> {
+        let (start, end) = range.into_inner();
+        if end < start {
+            return Ok(Range::new(start, T::default()));
+        }
+        Range::try_from_start_end(start, end.try_add(&T::from(1))?)
+    }
+}
+
+/// An empty range becomes `1..=0`, which is empty as well and can't underflow for unsigned types.
+impl<T: Integer> From<Range<T>> for std::ops::RangeInclusive<T> {
+    fn from(range: Range<T>) -> Self {
+        if range.is_empty() {
+            return T::from(1)..=T::default();
+        }
+        range.start()..=range.end() - T::from(1)
+    }
+}
+
+impl<T: Integer> IntoIterator for Range<T> {
+    type Item = T;
+    type IntoIter = RangeIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the numbers in a `Range`.
+#[derive(Debug, Clone)]
+pub struct RangeIter<T> {
+    next: T,
+    end: T,
+}
+
+impl<T> Iterator for RangeIter<T>
+where
+    T: PartialOrd + Clone + Add<Output = T> + From<u8>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.next >= self.end {
+            return None;
+        }
+        let current = self.next.clone();
+        self.next = current.clone() + T::from(1);
+        Some(current)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use num_bigint::BigInt;
 
     #[test]
     fn test_split_no_overlap() {
@@ -229,4 +349,99 @@ mod tests {
         // Assert
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_from_start_end() {
+        assert_eq!(Range::from_start_end(3, 7), Range::new(3, 4));
+        assert_eq!(Range::from_start_end(7, 3), Range::new(7, 0));
+        assert!(Range::from_start_end(7, 3).is_empty());
+        assert!(!Range::new(7, 1).is_empty());
+    }
+
+    #[test]
+    fn test_intersection() {
+        assert_eq!(Range::new(10, 10).intersection(&Range::new(15, 10)), Some(Range::new(15, 5)));
+        assert_eq!(Range::new(10, 10).intersection(&Range::new(12, 2)), Some(Range::new(12, 2)));
+        assert_eq!(Range::new(10, 10).intersection(&Range::new(20, 10)), None);
+        assert_eq!(Range::new(10, 10).intersection(&Range::new(12, 0)), None);
+        assert_eq!(Range::new(12, 0).intersection(&Range::new(10, 10)), None);
+    }
+
+    #[test]
+    fn test_std_range_conversions() {
+        // Arrange
+        let range = Range::new(3i64, 4);
+
+        // Act
+        let exclusive: std::ops::Range<i64> = range.into();
+        let inclusive: std::ops::RangeInclusive<i64> = range.into();
+
+        // Assert
+        assert_eq!(exclusive, 3..7);
+        assert_eq!(inclusive, 3..=6);
+        assert_eq!(Range::from(3..7), range);
+        assert_eq!(Range::try_from(3..=6), Ok(range));
+        assert!(std::ops::RangeInclusive::from(Range::new(3i64, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_inclusive_range_conversions_at_bounds() {
+        assert!(std::ops::RangeInclusive::from(Range::new(0u32, 0)).is_empty());
+        assert_eq!(std::ops::RangeInclusive::from(Range::new(0u8, 255)), 0..=254);
+        assert_eq!(Range::try_from(0..=254u8), Ok(Range::new(0, 255)));
+        assert_eq!(Range::try_from(0..=u8::MAX), Err(OverflowError { operation: "addition" }));
+        assert_eq!(Range::try_from(std::ops::RangeInclusive::new(5u8, 0)), Ok(Range::new(5, 0)));
+        assert_eq!(Range::try_from(i64::MIN..=i64::MIN), Ok(Range::new(i64::MIN, 1)));
+    }
+
+    #[test]
+    fn test_iter() {
+        assert_eq!(Range::new(-1i64, 4).into_iter().collect::<Vec<i64>>(), vec![-1, 0, 1, 2]);
+        assert_eq!(Range::new(5u32, 0).iter().count(), 0);
+    }
+
+    #[test]
+    fn test_ord_and_hash() {
+        // Arrange
+        let ranges = [Range::new(5, 1), Range::new(1, 3), Range::new(1, 2), Range::new(5, 1)];
+
+        // Act
+        let sorted: std::collections::BTreeSet<Range<i32>> = ranges.iter().copied().collect();
+        let hashed: std::collections::HashSet<Range<i32>> = ranges.iter().copied().collect();
+
+        // Assert
+        assert_eq!(sorted.into_iter().collect::<Vec<_>>(), vec![Range::new(1, 2), Range::new(1, 3), Range::new(5, 1)]);
+        assert_eq!(hashed.len(), 3);
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(Range::try_new(i64::MAX - 2, 2), Ok(Range::new(i64::MAX - 2, 2)));
+        assert!(Range::try_new(i64::MAX - 2, 3).is_err());
+        assert_eq!(Range::try_new(u128::MAX - 2, 3), Err(OverflowError { operation: "addition" }));
+        assert_eq!(Range::try_from_start_end(i64::MIN, i64::MAX), Err(OverflowError { operation: "subtraction" }));
+        assert_eq!(Range::try_from_start_end(i64::MAX, i64::MIN), Ok(Range::new(i64::MAX, 0)));
+        assert!(Range::new(5u128, 3).checked_shifted(&(u128::MAX - 7)).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "The end of the range doesn't fit")]
+    fn test_new_with_end_beyond_max() {
+        Range::new(i64::MAX, 1);
+    }
+
+    #[test]
+    fn test_big_int_range() {
+        // Arrange
+        let start: BigInt = BigInt::from(u128::MAX);
+        let range = Range::new(start.clone(), BigInt::from(10));
+
+        // Act
+        let parts = range.split(&Range::new(start.clone() + 5, BigInt::from(100)));
+
+        // Assert
+        assert_eq!(range.end(), start.clone() + 10);
+        assert!(range.contains(start.clone() + 9));
+        assert_eq!(parts, vec![Range::new(start.clone(), BigInt::from(5)), Range::new(start + 5, BigInt::from(5))]);
+    }
 }
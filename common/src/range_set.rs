@@ -0,0 +1,284 @@
+use crate::{Integer, OverflowError, Range};
+use std::fmt;
+use std::ops::Add;
+
+/// A set of numbers stored as disjoint ranges. The ranges are kept sorted, empty ranges are
+/// dropped, and ranges that overlap or touch are merged.
+#[derive(Clone, PartialEq)]
+pub struct RangeSet<T>
+where
+    T: PartialOrd + Clone,
+{
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> fmt::Debug for RangeSet<T>
+where
+    T: PartialOrd + Clone + Add<Output = T> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.ranges.iter()).finish()
+    }
+}
+
+impl<T> Default for RangeSet<T>
+where
+    T: PartialOrd + Clone,
+{
+    fn default() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+}
+
+/// Panics when merged ranges get too long for `T`, see `RangeSet::try_from_ranges`.
+impl<T: Integer> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        RangeSet::try_from_ranges(iter)
+            .unwrap_or_else(|error| panic!("The merged ranges don't fit: {}", error))
+    }
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create the set from the ranges, failing when ranges that touch are merged into a range
+    /// whose length doesn't fit in `T`.
+    pub fn try_from_ranges(ranges: impl IntoIterator<Item = Range<T>>) -> Result<Self, OverflowError> {
+        Ok(RangeSet { ranges: normalize(ranges.into_iter().collect())? })
+    }
+
+    /// The disjoint ranges of the set, sorted on start.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    /// Add the range to the set. On overflow the set is left unchanged.
+    pub fn insert(&mut self, range: Range<T>) -> Result<(), OverflowError> {
+        let mut ranges = self.ranges.clone();
+        ranges.push(range);
+        self.ranges = normalize(ranges)?;
+        Ok(())
+    }
+
+    pub fn contains(&self, n: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end() <= n);
+        self.ranges.get(index).is_some_and(|range| range.contains(n.clone()))
+    }
+
+    /// The total number of values in the set, failing when it doesn't fit in `T`.
+    pub fn len(&self) -> Result<T, OverflowError> {
+        self.ranges.iter().try_fold(T::default(), |total, range| total.try_add(&range.length()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> Result<RangeSet<T>, OverflowError> {
+        RangeSet::try_from_ranges(self.ranges.iter().chain(other.ranges.iter()).cloned())
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges: Vec<Range<T>> = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            if let Some(overlap) = a.intersection(b) {
+                ranges.push(overlap);
+            }
+            // Move on with the range that ends first, the other one may still overlap the next range.
+            if a.end() < b.end() { i += 1; } else { j += 1; }
+        }
+
+        RangeSet { ranges }
+    }
+
+    /// The values in `self` that are not in `other`.
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        // Per range, since a single range spanning the whole set might not fit in `T`. The ranges
+        // don't touch, so neither do the parts that are left of them.
+        let ranges: Vec<Range<T>> = self.ranges
+            .iter()
+            .flat_map(|range| other.complement(range.clone()).ranges)
+            .collect();
+
+        RangeSet { ranges }
+    }
+
+    /// The values within `bounds` that are not in the set. All parts lie within `bounds`, so
+    /// they can't overflow.
+    pub fn complement(&self, bounds: Range<T>) -> RangeSet<T> {
+        let mut ranges: Vec<Range<T>> = Vec::new();
+        let mut start = bounds.start();
+        for range in self.ranges.iter() {
+            if range.start() >= bounds.end() {
+                break;
+            }
+            if start < range.start() {
+                ranges.push(Range::from_start_end(start.clone(), range.start()));
+            }
+            if range.end() > start {
+                start = range.end();
+            }
+        }
+        if start < bounds.end() {
+            ranges.push(Range::from_start_end(start, bounds.end()));
+        }
+
+        RangeSet { ranges }
+    }
+}
+
+/// Sort the ranges and merge the ones that overlap or touch.
+fn normalize<T: Integer>(mut ranges: Vec<Range<T>>) -> Result<Vec<Range<T>>, OverflowError> {
+    ranges.retain(|range| range.length() > T::default());
+    ranges.sort_by(|a, b| a.start().partial_cmp(&b.start()).unwrap());
+
+    let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start() <= last.end() => {
+                if range.end() > last.end() {
+                    *last = Range::try_from_start_end(last.start(), range.end())?;
+                }
+            }
+            _ => merged.push(range),
+        }
+    }
+
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_set(ranges: &[(i64, i64)]) -> RangeSet<i64> {
+        ranges.iter().map(|(start, length)| Range::new(*start, *length)).collect()
+    }
+
+    #[test]
+    fn test_normalize() {
+        // Arrange
+        let expected = [Range::new(0, 10), Range::new(12, 3)];
+
+        // Act
+        let actual = create_set(&[(12, 3), (5, 5), (0, 6), (20, 0), (13, 1)]);
+
+        // Assert
+        assert_eq!(actual.ranges(), &expected[..]);
+    }
+
+    #[test]
+    fn test_insert() {
+        // Arrange
+        let mut set = create_set(&[(0, 2), (5, 2)]);
+
+        // Act
+        set.insert(Range::new(2, 3)).unwrap();
+
+        // Assert
+        assert_eq!(set.ranges(), &[Range::new(0, 7)]);
+    }
+
+    #[test]
+    fn test_contains() {
+        // Arrange
+        let set = create_set(&[(0, 2), (5, 2)]);
+
+        // Act & Assert
+        assert!(set.contains(0));
+        assert!(set.contains(6));
+        assert!(!set.contains(2));
+        assert!(!set.contains(7));
+        assert!(!set.contains(-1));
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(create_set(&[(0, 2), (5, 2), (1, 3)]).len(), Ok(6));
+        assert_eq!(RangeSet::<i64>::new().len(), Ok(0));
+        assert!(create_set(&[(i64::MIN, 1), (i64::MAX - 1, 1)]).len().is_ok());
+        assert!(create_set(&[(i64::MIN, i64::MAX), (0, i64::MAX)]).len().is_err());
+        assert!(RangeSet::<i64>::new().is_empty());
+    }
+
+    #[test]
+    fn test_union() {
+        // Arrange
+        let a = create_set(&[(0, 5), (10, 5)]);
+        let b = create_set(&[(5, 2), (20, 5)]);
+
+        // Act
+        let actual = a.union(&b).unwrap();
+
+        // Assert
+        assert_eq!(actual, create_set(&[(0, 7), (10, 5), (20, 5)]));
+    }
+
+    #[test]
+    fn test_intersection() {
+        // Arrange
+        let a = create_set(&[(0, 10), (20, 10)]);
+        let b = create_set(&[(5, 20), (28, 1)]);
+
+        // Act
+        let actual = a.intersection(&b);
+
+        // Assert
+        assert_eq!(actual, create_set(&[(5, 5), (20, 5), (28, 1)]));
+    }
+
+    #[test]
+    fn test_difference() {
+        // Arrange
+        let a = create_set(&[(0, 10), (20, 10)]);
+        let b = create_set(&[(-5, 7), (5, 2), (25, 10)]);
+
+        // Act
+        let actual = a.difference(&b);
+
+        // Assert
+        assert_eq!(actual, create_set(&[(2, 3), (7, 3), (20, 5)]));
+    }
+
+    #[test]
+    fn test_complement() {
+        // Arrange
+        let set = create_set(&[(-5, 7), (5, 2), (25, 10)]);
+
+        // Act
+        let actual = set.complement(Range::new(0, 30));
+
+        // Assert
+        assert_eq!(actual, create_set(&[(2, 3), (7, 18)]));
+    }
+
+    #[test]
+    fn test_merge_overflow() {
+        // Arrange
+        let mut set = create_set(&[(i64::MIN, i64::MAX)]);
+
+        // Act
+        let actual = set.insert(Range::new(-1, 2));
+
+        // Assert
+        assert_eq!(actual, Err(OverflowError { operation: "subtraction" }));
+        assert_eq!(set, create_set(&[(i64::MIN, i64::MAX)]));
+    }
+
+    #[test]
+    fn test_difference_over_full_width() {
+        // Arrange
+        let a = create_set(&[(i64::MIN, 10), (i64::MAX - 10, 10)]);
+        let b = create_set(&[(i64::MIN + 5, 10)]);
+
+        // Act
+        let actual = a.difference(&b);
+
+        // Assert
+        assert_eq!(actual, create_set(&[(i64::MIN, 5), (i64::MAX - 10, 10)]));
+    }
+}
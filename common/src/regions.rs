@@ -0,0 +1,228 @@
+use crate::{Grid, Point};
+use std::collections::VecDeque;
+
+/// Which cells count as neighbours when growing a region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Only the cells above, below, left and right.
+    Four,
+    /// Also the diagonal cells.
+    Eight,
+}
+
+/// The smallest rectangle containing a set of points, `min` and `max` are both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl BoundingBox {
+    pub fn width(&self) -> u64 {
+        (self.max.x - self.min.x) as u64 + 1
+    }
+
+    pub fn height(&self) -> u64 {
+        (self.max.y - self.min.y) as u64 + 1
+    }
+}
+
+/// A set of connected cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    cells: Vec<Point>,
+    bounding_box: BoundingBox,
+}
+
+impl Region {
+    fn new(mut cells: Vec<Point>) -> Self {
+        // Sort row by row, so the cells come in reading order.
+        cells.sort_by_key(|p| (p.y, p.x));
+        let bounding_box = BoundingBox {
+            min: Point::new(cells.iter().map(|p| p.x).min().unwrap(), cells.iter().map(|p| p.y).min().unwrap()),
+            max: Point::new(cells.iter().map(|p| p.x).max().unwrap(), cells.iter().map(|p| p.y).max().unwrap()),
+        };
+        Region { cells, bounding_box }
+    }
+
+    /// The cells of the region in reading order: row by row, left to right.
+    pub fn cells(&self) -> &[Point] {
+        &self.cells
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox {
+        self.bounding_box
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+}
+
+/// The result of labelling all cells of a grid with the region they are part of.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Components {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+impl Components {
+    /// For every cell the index of its region in `regions`.
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    /// All regions, ordered by the first cell in reading order.
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    pub fn region_at(&self, p: Point) -> Option<&Region> {
+        self.labels.at_point(p).map(|label| &self.regions[*label])
+    }
+}
+
+/// Find the region containing `start`. Two neighbouring cells are in the same region when
+/// `belong_together` holds for them.
+pub fn flood_fill<T>(grid: &Grid<T>, start: Point, connectivity: Connectivity,
+                     belong_together: impl Fn((Point, &T), (Point, &T)) -> bool) -> Option<Region> {
+    let mut is_visited: Grid<bool> = Grid::new(grid.width(), grid.height(), false);
+    fill(grid, start, connectivity, &belong_together, &mut is_visited)
+}
+
+/// Split the whole grid into regions. Every cell ends up in exactly one region, cells that don't
+/// belong together with any neighbour form a region of their own.
+pub fn connected_components<T>(grid: &Grid<T>, connectivity: Connectivity,
+                               belong_together: impl Fn((Point, &T), (Point, &T)) -> bool) -> Components {
+    let mut is_visited: Grid<bool> = Grid::new(grid.width(), grid.height(), false);
+    let mut labels: Grid<usize> = Grid::new(grid.width(), grid.height(), 0);
+    let mut regions: Vec<Region> = Vec::new();
+
+    for (p, _) in grid.iter() {
+        if *is_visited.at_point(p).unwrap() {
+            continue;
+        }
+        let region = fill(grid, p, connectivity, &belong_together, &mut is_visited).unwrap();
+        for cell in region.cells() {
+            *labels.at_point_mut(*cell).unwrap() = regions.len();
+        }
+        regions.push(region);
+    }
+
+    Components { labels, regions }
+}
+
+fn fill<T>(grid: &Grid<T>, start: Point, connectivity: Connectivity,
+           belong_together: &impl Fn((Point, &T), (Point, &T)) -> bool,
+           is_visited: &mut Grid<bool>) -> Option<Region> {
+    grid.at_point(start)?;
+    *is_visited.at_point_mut(start).unwrap() = true;
+    let mut cells: Vec<Point> = vec![start];
+    let mut queue: VecDeque<Point> = VecDeque::from([start]);
+
+    while let Some(current) = queue.pop_front() {
+        let current_cell = (current, grid.at_point(current).unwrap());
+        let neighbours: Vec<Point> = match connectivity {
+            Connectivity::Four => grid.neighbours4(current).collect(),
+            Connectivity::Eight => grid.neighbours8(current).collect(),
+        };
+        for neighbour in neighbours {
+            if *is_visited.at_point(neighbour).unwrap() {
+                continue;
+            }
+            if belong_together(current_cell, (neighbour, grid.at_point(neighbour).unwrap())) {
+                *is_visited.at_point_mut(neighbour).unwrap() = true;
+                cells.push(neighbour);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    Some(Region::new(cells))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn same_char((_, a): (Point, &char), (_, b): (Point, &char)) -> bool {
+        a == b
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("aab\nbab\nbba".lines());
+        let expected = [Point::new(0, 0), Point::new(1, 0), Point::new(1, 1)];
+
+        // Act
+        let actual = flood_fill(&grid, Point::new(0, 0), Connectivity::Four, same_char).unwrap();
+
+        // Assert
+        assert_eq!(actual.cells(), &expected[..]);
+        assert_eq!(actual.bounding_box(), BoundingBox { min: Point::new(0, 0), max: Point::new(1, 1) });
+    }
+
+    #[test]
+    fn test_flood_fill_eight_connectivity() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("aab\nbab\nbba".lines());
+
+        // Act
+        let actual = flood_fill(&grid, Point::new(0, 0), Connectivity::Eight, same_char).unwrap();
+
+        // Assert
+        assert_eq!(actual.len(), 4);
+        assert_eq!(actual.bounding_box().width(), 3);
+        assert_eq!(actual.bounding_box().height(), 3);
+    }
+
+    #[test]
+    fn test_flood_fill_outside_grid() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("ab".lines());
+
+        // Act
+        let actual = flood_fill(&grid, Point::new(-1, 0), Connectivity::Four, same_char);
+
+        // Assert
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn test_connected_components() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("aab\nbab\nbba".lines());
+        let expected_labels: Grid<usize> = Grid::from(vec![
+            vec![0, 0, 1],
+            vec![2, 0, 1],
+            vec![2, 2, 3],
+        ]);
+
+        // Act
+        let actual = connected_components(&grid, Connectivity::Four, same_char);
+
+        // Assert
+        assert_eq!(actual.regions().len(), 4);
+        assert_eq!(*actual.labels(), expected_labels);
+        assert_eq!(actual.region_at(Point::new(0, 2)).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_connected_components_on_same_row() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("11.\n1.2".lines());
+
+        // Act
+        let actual = connected_components(&grid, Connectivity::Four,
+                                          |(p, a), (q, b)| p.y == q.y && a.is_ascii_digit() && b.is_ascii_digit());
+
+        // Assert
+        let sizes: Vec<usize> = actual.regions().iter().map(|region| region.len()).collect();
+        assert_eq!(sizes, vec![2, 1, 1, 1, 1]);
+    }
+}
@@ -0,0 +1,26 @@
+use crate::Answer;
+use std::str::Lines;
+
+/// A solution for one day of the puzzle. The input is parsed once with `parse`, after which
+/// both parts work on the parsed input. This split allows timing parsing separately.
+pub trait Solution {
+    /// The number of the day, used to find the input file.
+    const DAY: u8;
+
+    /// The parsed puzzle input. It may borrow from the lines it was parsed from.
+    type Input<'a>;
+
+    fn parse(lines: Lines<'_>) -> Self::Input<'_>;
+
+    fn part1(input: &Self::Input<'_>) -> Answer;
+
+    fn part2(input: &Self::Input<'_>) -> Answer;
+
+    fn solve_part1(lines: Lines<'_>) -> Answer {
+        Self::part1(&Self::parse(lines))
+    }
+
+    fn solve_part2(lines: Lines<'_>) -> Answer {
+        Self::part2(&Self::parse(lines))
+    }
+}
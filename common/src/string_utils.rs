@@ -1,25 +1,30 @@
 use std::str::Lines;
 
-pub fn split_into_blocks(lines: Lines) -> Vec<Vec<&str>> {
-    let mut blocks: Vec<Vec<&str>> = Vec::new();
-    let mut current_block: Vec<&str> = Vec::new();
+/// Split the lines into blocks separated by one or more empty lines.
+pub fn split_into_blocks(lines: Lines<'_>) -> Vec<Vec<&str>> {
+    split_into_numbered_blocks(lines)
+        .into_iter()
+        .map(|(_, block)| block)
+        .collect()
+}
 
-    for line in lines {
+/// Like `split_into_blocks`, together with the line number of the first line of each block.
+/// Line numbers start at 1, so they can be used in error messages.
+pub fn split_into_numbered_blocks(lines: Lines<'_>) -> Vec<(usize, Vec<&str>)> {
+    let mut blocks: Vec<(usize, Vec<&str>)> = Vec::new();
+    let mut is_in_block = false;
+
+    for (index, line) in lines.enumerate() {
         if line.trim().is_empty() {
-            if !current_block.is_empty() {
-                blocks.push(current_block);
-                current_block = Vec::new();
-            }
+            is_in_block = false;
+        } else if is_in_block {
+            blocks.last_mut().unwrap().1.push(line);
         } else {
-            current_block.push(line);
+            blocks.push((index + 1, vec![line]));
+            is_in_block = true;
         }
     }
 
-    // Push the last block if it has content
-    if !current_block.is_empty() {
-        blocks.push(current_block);
-    }
-
     blocks
 }
 
@@ -60,4 +65,16 @@ This is line 5
 
         assert_eq!(result, expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_split_into_numbered_blocks() {
+        // Arrange
+        let input = "\nline 2\nline 3\n\n\nline 6\n";
+
+        // Act
+        let result = split_into_numbered_blocks(input.lines());
+
+        // Assert
+        assert_eq!(result, vec![(2, vec!["line 2", "line 3"]), (6, vec!["line 6"])]);
+    }
+}
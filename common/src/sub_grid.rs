@@ -0,0 +1,115 @@
+use crate::{Grid, Point};
+
+/// A borrowed rectangular part of a Grid. Positions are relative to the top left corner
+/// of the sub-grid.
+#[derive(Debug, Clone, Copy)]
+pub struct SubGrid<'a, T> {
+    grid: &'a Grid<T>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> SubGrid<'a, T> {
+    /// Use Grid::sub_grid to create a SubGrid, which checks that it fits within the grid.
+    pub(crate) fn new(grid: &'a Grid<T>, x: usize, y: usize, width: usize, height: usize) -> Self {
+        SubGrid { grid, x, y, width, height }
+    }
+
+    pub fn at(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x < self.width && y < self.height {
+            self.grid.at(self.x + x, self.y + y)
+        } else {
+            None
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Iterate over all cells row by row, together with their position within the sub-grid.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &'a T)> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width)
+                .map(move |x| (Point::new(x as i64, y as i64), self.at(x, y).unwrap())))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&'a [T]> {
+        if y >= self.height {
+            return None;
+        }
+        self.grid
+            .row(self.y + y)
+            .map(|row| &row[self.x..self.x + self.width])
+    }
+}
+
+impl<T> SubGrid<'_, T> where T: Clone {
+    /// Copy the cells of the view into a new Grid.
+    pub fn to_grid(&self) -> Grid<T> {
+        let rows: Vec<Vec<T>> = (0..self.height)
+            .map(|y| self.row(y).unwrap().to_vec())
+            .collect();
+        Grid::from(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sub_grid() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abcd\nefgh\nijkl\n".lines());
+
+        // Act
+        let sub_grid: SubGrid<char> = grid.sub_grid(1, 1, 2, 2).unwrap();
+
+        // Assert
+        assert_eq!(sub_grid.width(), 2);
+        assert_eq!(sub_grid.height(), 2);
+        assert_eq!(sub_grid.at(0, 0), Some(&'f'));
+        assert_eq!(sub_grid.at(1, 1), Some(&'k'));
+        assert_eq!(sub_grid.at(2, 0), None);
+        assert_eq!(sub_grid.row(1), Some(&['j', 'k'][..]));
+    }
+
+    #[test]
+    fn test_iter() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abcd\nefgh\nijkl\n".lines());
+        let sub_grid: SubGrid<char> = grid.sub_grid(2, 0, 2, 2).unwrap();
+        let expected = vec![
+            (Point::new(0, 0), &'c'),
+            (Point::new(1, 0), &'d'),
+            (Point::new(0, 1), &'g'),
+            (Point::new(1, 1), &'h'),
+        ];
+
+        // Act
+        let actual: Vec<(Point, &char)> = sub_grid.iter().collect();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_to_grid() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abcd\nefgh\nijkl\n".lines());
+        let expected: Grid<char> = Grid::parse("bc\nfg\n".lines());
+
+        // Act
+        let actual = grid.sub_grid(1, 0, 2, 2).unwrap().to_grid();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
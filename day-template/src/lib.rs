@@ -0,0 +1,55 @@
+use std::str::Lines;
+use common::{Answer, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(lines: Lines<'_>) -> Self::Input<'_> {
+        lines.collect()
+    }
+
+    fn part1(_: &Self::Input<'_>) -> Answer {
+        0.into()
+    }
+
+    fn part2(_: &Self::Input<'_>) -> Answer {
+        0.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#""#;
+
+    #[test]
+    fn test_solve_part1()
+    {
+        // Arrange
+        let expected = Answer::from(0);
+
+        // Act
+        let actual: Answer = Day08::solve_part1(INPUT.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2()
+    {
+        // Arrange
+        let expected = Answer::from(0);
+
+        // Act
+        let actual: Answer = Day08::solve_part2(INPUT.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,194 @@
+use aho_corasick::{AhoCorasick, BuildError};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+/// The languages with a built-in word list, see `DigitMatcher::for_language`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    English,
+    Dutch,
+    German,
+}
+
+impl Language {
+    /// The words for the digits, the word for digit `n` is at index `n`.
+    pub fn words(&self) -> [&'static str; 10] {
+        match self {
+            Language::English => ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"],
+            Language::Dutch => ["nul", "een", "twee", "drie", "vier", "vijf", "zes", "zeven", "acht", "negen"],
+            Language::German => ["null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"],
+        }
+    }
+}
+
+/// Returned when a custom word list can't be used to find digits.
+#[derive(Debug)]
+pub enum DigitMatcherError {
+    EmptyWord { digit: u8 },
+    InvalidDigit { word: String, digit: u8 },
+    /// The same word is used for two different digits.
+    AmbiguousWord { word: String, first: u8, second: u8 },
+    Automaton(BuildError),
+}
+
+impl fmt::Display for DigitMatcherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DigitMatcherError::EmptyWord { digit } =>
+                write!(f, "The word for digit {} is empty", digit),
+            DigitMatcherError::InvalidDigit { word, digit } =>
+                write!(f, "'{}' is used for {}, which is not a digit", word, digit),
+            DigitMatcherError::AmbiguousWord { word, first, second } =>
+                write!(f, "'{}' is used for both {} and {}", word, first, second),
+            DigitMatcherError::Automaton(error) =>
+                write!(f, "Can't build the automaton for the words: {}", error),
+        }
+    }
+}
+
+impl Error for DigitMatcherError {}
+
+/// Finds the digits in a line, written either as a digit or as one of the configured words.
+/// All words are matched together by an Aho-Corasick automaton, so a line is scanned once, and
+/// overlapping words like `eightwo` are both found.
+pub struct DigitMatcher {
+    automaton: AhoCorasick,
+    /// The digit for each pattern of the automaton, by pattern index.
+    digits: Vec<u8>,
+}
+
+impl DigitMatcher {
+    /// Create a matcher for the words with their digits, the digits `0`..`9` themselves are always
+    /// matched as well.
+    pub fn new<'a>(words: impl IntoIterator<Item = (&'a str, u8)>) -> Result<Self, DigitMatcherError> {
+        let mut digit_by_word: HashMap<String, u8> = (0..10u8)
+            .map(|digit| (digit.to_string(), digit))
+            .collect();
+        let mut patterns: Vec<String> = (0..10u8).map(|digit| digit.to_string()).collect();
+        for (word, digit) in words {
+            if digit > 9 {
+                return Err(DigitMatcherError::InvalidDigit { word: word.to_string(), digit });
+            }
+            if word.is_empty() {
+                return Err(DigitMatcherError::EmptyWord { digit });
+            }
+            match digit_by_word.get(word) {
+                Some(first) if *first != digit => {
+                    return Err(DigitMatcherError::AmbiguousWord { word: word.to_string(), first: *first, second: digit });
+                }
+                Some(_) => {}
+                None => {
+                    digit_by_word.insert(word.to_string(), digit);
+                    patterns.push(word.to_string());
+                }
+            }
+        }
+
+        let automaton = AhoCorasick::new(&patterns).map_err(DigitMatcherError::Automaton)?;
+        let digits: Vec<u8> = patterns.iter().map(|pattern| digit_by_word[pattern]).collect();
+        Ok(DigitMatcher { automaton, digits })
+    }
+
+    /// A matcher that only finds the digits themselves.
+    pub fn digits_only() -> Self {
+        Self::new([]).unwrap()
+    }
+
+    pub fn for_language(language: Language) -> Self {
+        let words = language.words();
+        Self::new(words.iter().zip(0..).map(|(word, digit)| (*word, digit))).unwrap()
+    }
+
+    /// All digits in the line, in the order in which they start.
+    pub fn find_digits(&self, line: &str) -> Vec<u8> {
+        let mut matches: Vec<(usize, u8)> = self.automaton
+            .find_overlapping_iter(line)
+            .map(|found| (found.start(), self.digits[found.pattern().as_usize()]))
+            .collect();
+        // The matches are found in the order in which they end.
+        matches.sort_by_key(|(start, _)| *start);
+        matches.into_iter().map(|(_, digit)| digit).collect()
+    }
+
+    /// The first digit and the last digit of the line combined into a two-digit number,
+    /// 0 when there are no digits.
+    pub fn calibration_value(&self, line: &str) -> u8 {
+        let digits = self.find_digits(line);
+        match (digits.first(), digits.last()) {
+            (Some(first), Some(last)) => first * 10 + last,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_digits_overlapping_words() {
+        // Arrange
+        let matcher = DigitMatcher::for_language(Language::English);
+
+        // Act
+        let actual = matcher.find_digits("zoneightwo3zero");
+
+        // Assert
+        assert_eq!(actual, vec![1, 8, 2, 3, 0]);
+    }
+
+    #[test]
+    fn test_calibration_value_english() {
+        run_calibration_value_test_case(Language::English, "eightwothree", 83);
+        run_calibration_value_test_case(Language::English, "xtwone3four", 24);
+        run_calibration_value_test_case(Language::English, "sevenine", 79);
+        run_calibration_value_test_case(Language::English, "zero", 0);
+        run_calibration_value_test_case(Language::English, "nothing", 0);
+    }
+
+    #[test]
+    fn test_calibration_value_dutch() {
+        run_calibration_value_test_case(Language::Dutch, "tweeenzeven", 27);
+        run_calibration_value_test_case(Language::Dutch, "acht5negenul", 80);
+    }
+
+    #[test]
+    fn test_calibration_value_german() {
+        run_calibration_value_test_case(Language::German, "fünfundzwanzigdrei", 53);
+        run_calibration_value_test_case(Language::German, "neunzehn", 99);
+    }
+
+    fn run_calibration_value_test_case(language: Language, line: &str, expected: u8) {
+        // Arrange
+        let matcher = DigitMatcher::for_language(language);
+
+        // Act
+        let actual = matcher.calibration_value(line);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_custom_words() {
+        // Arrange
+        let matcher = DigitMatcher::new([("I", 1), ("V", 5), ("X", 9)]).unwrap();
+
+        // Act
+        let actual = matcher.calibration_value("aVbIc");
+
+        // Assert
+        assert_eq!(actual, 51);
+        assert_eq!(matcher.calibration_value("one2"), 22);
+    }
+
+    #[test]
+    fn test_invalid_words() {
+        assert!(matches!(DigitMatcher::new([("ten", 10)]), Err(DigitMatcherError::InvalidDigit { digit: 10, .. })));
+        assert!(matches!(DigitMatcher::new([("", 3)]), Err(DigitMatcherError::EmptyWord { digit: 3 })));
+        assert!(matches!(DigitMatcher::new([("een", 1), ("een", 8)]),
+                         Err(DigitMatcherError::AmbiguousWord { first: 1, second: 8, .. })));
+        assert!(matches!(DigitMatcher::new([("7", 1)]), Err(DigitMatcherError::AmbiguousWord { .. })));
+    }
+}
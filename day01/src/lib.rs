@@ -0,0 +1,126 @@
+use std::str::Lines;
+use common::{Answer, Solution};
+
+mod digit_matcher;
+
+pub use crate::digit_matcher::{DigitMatcher, DigitMatcherError, Language};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(lines: Lines<'_>) -> Self::Input<'_> {
+        lines.collect()
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> Answer {
+        let matcher = DigitMatcher::digits_only();
+        lines.iter()
+            .map(|line| matcher.calibration_value(line) as u32)
+            .sum::<u32>()
+            .into()
+    }
+
+    /// Digits can also be spelled out as English words.
+    fn part2(lines: &Self::Input<'_>) -> Answer {
+        let matcher = get_spelled_digit_matcher();
+        lines.iter()
+            .map(|line| matcher.calibration_value(line) as u32)
+            .sum::<u32>()
+            .into()
+    }
+}
+
+/// The puzzle only spells out `one` up to `nine`, `zero` is not a digit there.
+fn get_spelled_digit_matcher() -> DigitMatcher {
+    let words = Language::English.words();
+    DigitMatcher::new(words.into_iter().zip(0..).skip(1))
+        .expect("The English words are valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_calibration_value_outsides() {
+        run_get_calibration_value_test_case("1abc2", 12);
+    }
+
+    #[test]
+    fn test_get_calibration_value_single() {
+        //noinspection SpellCheckingInspection
+        run_get_calibration_value_test_case("treb7uchet", 77);
+    }
+
+    #[test]
+    fn test_solve_part1() {
+        // Arrange
+        let input = r#"1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet"#;
+        let expected = Answer::from(142);
+
+        // Act
+        let actual: Answer = Day01::solve_part1(input.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        // Arrange
+        let input = r#"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen"#;
+        let expected = Answer::from(281);
+
+        // Act
+        let actual: Answer = Day01::solve_part2(input.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_get_real_calibration_value_unused_digit()
+    {
+        run_get_real_calibration_value_test_case("two1nine", 29);
+    }
+
+    #[test]
+    fn test_get_real_calibration_value_zero_is_not_a_digit()
+    {
+        run_get_real_calibration_value_test_case("zero5", 55);
+        run_get_real_calibration_value_test_case("3zero", 33);
+    }
+
+    fn run_get_calibration_value_test_case(input: &str, expected: u8) {
+        // Arrange
+
+        // Act
+        let actual = DigitMatcher::digits_only().calibration_value(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    fn run_get_real_calibration_value_test_case(input: &str, expected: u8) {
+        // Arrange
+
+        // Act
+        let actual = get_spelled_digit_matcher().calibration_value(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
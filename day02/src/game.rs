@@ -44,7 +44,7 @@ impl Game {
 
         let game_sets: Vec<GameSet> = parts[1]
             .split(';')
-            .map(|game_set_text|GameSet::parse(game_set_text))
+            .map(GameSet::parse)
             .collect();
 
         Game {
@@ -72,7 +72,7 @@ impl Game {
             .map(|color| self.game_sets.iter()
                 .map(|game_set| game_set.get_nr_cubes(color))
                 .max().unwrap())
-            .fold(1, |acc, max| acc * max)
+            .product()
     }
 }
 
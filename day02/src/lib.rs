@@ -0,0 +1,77 @@
+mod cube_color;
+
+mod game_set;
+mod game;
+
+use crate::game::Game;
+use common::{Answer, Solution};
+use std::str::{Lines};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game>;
+
+    fn parse(lines: Lines<'_>) -> Self::Input<'_> {
+        lines
+            .map(Game::parse)
+            .collect()
+    }
+
+    fn part1(games: &Self::Input<'_>) -> Answer {
+        games.iter()
+            .filter(|game| game.is_possible_part1())
+            .map(|game| game.id().to_u32())
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(games: &Self::Input<'_>) -> Answer {
+        games.iter()
+            .map(|game| game.power())
+            .sum::<u32>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part1()
+    {
+        // Arrange
+        let input = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+        let expected = Answer::from(8);
+
+        // Act
+        let actual: Answer = Day02::solve_part1(input.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        // Arrange
+        let input = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+        let expected = Answer::from(2286);
+
+        // Act
+        let actual: Answer = Day02::solve_part2(input.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
\ No newline at end of file
@@ -21,76 +21,49 @@ impl Engine {
         &self.part_nrs
     }
 
-    fn get_part_numbers(grid: &EngineGrid) -> Vec<PartNr> {
-        let mut part_numbers: Vec<PartNr> = Vec::new();
-        let mut current_number: Option<u32> = None;
-        let mut current_pos = Point::new(0, 0);
-        let mut is_adjacent_to_symbol = false;
-        for y in 0..grid.height() {
-            for x in 0..grid.width() {
-                let ch: &char = grid.at(x, y).unwrap();
-                if ch.is_digit(10) {
-                    if current_number.is_none() {
-                        current_number = Some(ch.to_digit(10).unwrap());
-                        current_pos = Point::new(x as i64, y as i64);
-                        is_adjacent_to_symbol = grid.is_adjacent_to_symbol(x, y);
-                    }
-                    else {
-                        let new_number = 10 * current_number.unwrap() +
-                            ch.to_digit(10).unwrap();
-                        current_number = Some(new_number);
-                        is_adjacent_to_symbol = is_adjacent_to_symbol || grid.is_adjacent_to_symbol(x, y);
-                    }
-                }
-                else {
-                    if let Some(nr) = current_number {
-                        if is_adjacent_to_symbol {
-                            part_numbers.push(PartNr::new(nr, current_pos));
-                        }
-                    }
-                    current_number = None;
-                }
-            }
-            if let Some(nr) = current_number {
-                if is_adjacent_to_symbol {
-                    part_numbers.push(PartNr::new(nr, current_pos));
-                }
-            }
-            current_number = None;
-        }
+    /// Render the engine schematic with the digits of all part numbers shown through `highlight`,
+    /// for example `common::ansi_highlight` to see which numbers were found in the terminal.
+    pub fn render_part_numbers(&self, highlight: impl Fn(&char) -> String) -> String {
+        let points = self.part_nrs.iter().flat_map(|part_nr| part_nr.points());
+        self.grid.render_highlighted(points, highlight)
+    }
 
-        part_numbers
+    fn get_part_numbers(grid: &EngineGrid) -> Vec<PartNr> {
+        grid.number_regions()
+            .into_iter()
+            .filter(|region| region.cells().iter().any(|p| grid.is_adjacent_to_symbol(*p)))
+            .map(|region| {
+                let nr = region.cells()
+                    .iter()
+                    .fold(0, |nr, p| 10 * nr + grid.at(*p).unwrap().to_digit(10).unwrap());
+                PartNr::new(nr, region.bounding_box().min)
+            })
+            .collect()
     }
 
     pub fn get_gears(&self) -> Vec<Gear> {
-        let mut gears: Vec<Gear> = Vec::new();
-        for y in 0..self.grid.height() {
-            for x in 0..self.grid.width() {
-                if let Some(gear) = self.is_gear_at(x, y) {
-                    gears.push(gear);
-                }
-            }
-        }
-        gears
+        self.grid
+            .gear_symbol_positions()
+            .into_iter()
+            .filter_map(|p| self.get_gear_at(p))
+            .collect()
     }
 
-    fn is_gear_at(&self, x: usize, y: usize) -> Option<Gear> {
-        if self.grid.is_gear_symbol(x, y) {
-            let part_nrs: Vec<PartNr> = self.get_part_numbers_adjacent_at(x, y);
-            if part_nrs.len() == 2 {
-                return Some(Gear::new(part_nrs[0], part_nrs[1]));
-            }
+    fn get_gear_at(&self, p: Point) -> Option<Gear> {
+        let part_nrs: Vec<PartNr> = self.get_part_numbers_adjacent_at(p);
+        if part_nrs.len() == 2 {
+            return Some(Gear::new(part_nrs[0], part_nrs[1]));
         }
 
         None
     }
 
-    fn get_part_numbers_adjacent_at(&self, x: usize, y: usize) -> Vec<PartNr> {
+    fn get_part_numbers_adjacent_at(&self, p: Point) -> Vec<PartNr> {
         // Using IndexSet (from crate 'indexset') to maintain insertion order,
         // makes it a bit easier to test the code and make it deterministic.
         let mut part_numbers: IndexSet<PartNr> = IndexSet::new();
 
-        for adjacent_point in Point::new(x as i64, y as i64).adjacent_points() {
+        for adjacent_point in p.adjacent_points() {
             if let Some(part_number) = self.get_part_number_at(adjacent_point) {
                 part_numbers.insert(part_number);
             }
@@ -124,12 +97,12 @@ mod tests {
         run_is_symbol_test_case(1, 1, false);
     }
 
-    fn run_is_symbol_test_case(x: usize, y: usize, expected: bool) {
+    fn run_is_symbol_test_case(x: i64, y: i64, expected: bool) {
         // Arrange
         let engine: EngineGrid = EngineGrid::parse("0*2\n@.^".lines());
 
         // Act
-        let actual = engine.is_symbol(x, y);
+        let actual = engine.is_symbol(Point::new(x, y));
 
         // Assert
         assert_eq!(actual, expected);
@@ -145,7 +118,7 @@ mod tests {
         run_is_adjacent_to_symbol_test_case(6, 1, false);
     }
 
-    fn run_is_adjacent_to_symbol_test_case(x: usize, y: usize, expected: bool) {
+    fn run_is_adjacent_to_symbol_test_case(x: i64, y: i64, expected: bool) {
         // Arrange
         let engine_text = r#"...*......
 ..35..633.
@@ -153,7 +126,7 @@ mod tests {
         let engine: EngineGrid = EngineGrid::parse(engine_text.lines());
 
         // Act
-        let actual = engine.is_adjacent_to_symbol(x, y);
+        let actual = engine.is_adjacent_to_symbol(Point::new(x, y));
 
         // Assert
         assert_eq!(actual, expected);
@@ -219,4 +192,22 @@ mod tests {
         // Assert
         assert_eq!(actual, expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_render_part_numbers() {
+        // Arrange
+        let engine_text = r#"467..114..
+...*......
+..35..633."#;
+        let engine = Engine::parse(engine_text.lines());
+        let expected = r#"###..114..
+...*......
+..##..633."#;
+
+        // Act
+        let actual = engine.render_part_numbers(|_| "#".to_string());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
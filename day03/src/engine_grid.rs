@@ -1,5 +1,5 @@
 use std::str::Lines;
-use common::Grid;
+use common::{connected_components, Connectivity, Grid, Point, Region};
 
 pub struct EngineGrid {
     grid: Grid<char>
@@ -11,44 +11,41 @@ impl EngineGrid {
         EngineGrid { grid }
     }
 
-    pub fn width(&self) -> usize {
-        self.grid.width()
+    pub fn at(&self, p: Point) -> Option<&char> {
+        self.grid.at_point(p)
     }
 
-    pub fn height(&self) -> usize {
-        self.grid.height()
+    pub fn is_adjacent_to_symbol(&self, p: Point) -> bool {
+        self.grid
+            .neighbours8(p)
+            .any(|neighbour| self.is_symbol(neighbour))
     }
 
-    pub fn at(&self, x: usize, y: usize) -> Option<&char> {
-        self.grid.at(x, y)
+    pub fn is_symbol(&self, p: Point) -> bool {
+        let non_symbols = "0123456789.";
+        self.grid
+            .at_point(p)
+            .is_some_and(|ch: &char| !non_symbols.contains(*ch))
     }
 
-    pub fn is_adjacent_to_symbol(&self, x: usize, y: usize) -> bool {
-        for dx in -1i32..=1i32 {
-            for dy in -1i32..=1i32 {
-                let new_x: i32 = x as i32 + dx;
-                let new_y: i32 = y as i32 + dy;
-                if (dx != 0 || dy != 0) && new_x >= 0 && new_y >= 0 {
-                    if self.is_symbol(new_x as usize, new_y as usize) {
-                        return true;
-                    }
-                }
-            }
-        }
-
-        false
+    pub fn render_highlighted(&self, highlighted: impl IntoIterator<Item = Point>,
+                              highlight: impl Fn(&char) -> String) -> String {
+        self.grid.render_highlighted(highlighted, highlight)
     }
 
-    pub fn is_symbol(&self, x: usize, y: usize) -> bool {
-        let non_symbols = "0123456789.";
-        self.grid
-            .at(x, y)
-            .map_or(false, |ch: &char| !non_symbols.contains(*ch))
+    pub fn gear_symbol_positions(&self) -> Vec<Point> {
+        self.grid.positions(|ch: &char| *ch == '*')
     }
 
-    pub fn is_gear_symbol(&self, x: usize, y: usize) -> bool {
-        self.grid
-            .at(x, y)
-            .map_or(false, |ch: &char| *ch == '*')
+    /// The regions of horizontally adjacent digits, each of them is a number, in reading order.
+    pub fn number_regions(&self) -> Vec<Region> {
+        connected_components(&self.grid, Connectivity::Four, |(p, a), (q, b)| {
+            p.y == q.y && a.is_ascii_digit() && b.is_ascii_digit()
+        })
+            .regions()
+            .iter()
+            .filter(|region| self.grid.at_point(region.cells()[0]).unwrap().is_ascii_digit())
+            .cloned()
+            .collect()
     }
 }
@@ -0,0 +1,80 @@
+mod engine;
+mod part_nr;
+mod gear;
+mod engine_grid;
+
+use std::str::Lines;
+use common::{Answer, Solution};
+use crate::engine::Engine;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Engine;
+
+    fn parse(lines: Lines<'_>) -> Self::Input<'_> {
+        Engine::parse(lines)
+    }
+
+    fn part1(engine: &Self::Input<'_>) -> Answer {
+        engine
+            .part_numbers()
+            .iter()
+            .map(|part_nr| part_nr.nr())
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(engine: &Self::Input<'_>) -> Answer {
+        engine
+            .get_gears()
+            .iter()
+            .map(|gear| gear.ratio())
+            .sum::<u32>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+
+    #[test]
+    fn test_solve_part1()
+    {
+        // Arrange
+        let expected = Answer::from(4361);
+
+        // Act
+        let actual: Answer = Day03::solve_part1(INPUT.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2()
+    {
+        // Arrange
+        let expected = Answer::from(467835);
+
+        // Act
+        let actual: Answer = Day03::solve_part2(INPUT.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -17,6 +17,11 @@ impl PartNr {
         self.nr
     }
 
+    /// The points covered by the digits of this part number.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.len as i64).map(|dx| self.position.translate(dx, 0))
+    }
+
     pub fn contains_point(&self, p: Point) -> bool {
         p.y == self.position.y &&
             p.x >= self.position.x &&
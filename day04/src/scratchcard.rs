@@ -17,7 +17,7 @@ impl Scratchcard {
 
     fn parse_numbers(numbers_text: &str) -> Vec<u32> {
         numbers_text.split(' ')
-            .filter(|item| item.len() > 0)
+            .filter(|item| !item.is_empty())
             .map(|item| item.parse::<u32>().unwrap())
             .collect()
     }
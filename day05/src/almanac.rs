@@ -0,0 +1,287 @@
+use common::{split_into_numbered_blocks, Graph, Integer, RangeSet};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::str::{FromStr, Lines};
+use crate::multi_range_map::{MultiRangeMap, NotInjectiveError};
+use crate::parse_error::{parse_number, ParseError};
+
+/// Everything that can go wrong while mapping between categories.
+#[derive(Debug, PartialEq)]
+pub enum AlmanacError<T: Integer> {
+    NoRoute { from: String, to: String },
+    NotInjective(NotInjectiveError<T>),
+}
+
+impl<T: Integer> fmt::Display for AlmanacError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlmanacError::NoRoute { from, to } =>
+                write!(f, "There is no way to map from '{}' to '{}'", from, to),
+            AlmanacError::NotInjective(error) =>
+                write!(f, "The map can't be inverted: {}", error),
+        }
+    }
+}
+
+impl<T: Integer> Error for AlmanacError<T> {}
+
+/// A map with the categories from its `X-to-Y map:` header.
+struct CategoryMap<T: Integer> {
+    source: String,
+    destination: String,
+    multi_range_map: MultiRangeMap<T>,
+}
+
+/// The parsed almanac: the seed numbers, the maps, and which category can be mapped to which.
+pub struct Almanac<T: Integer> {
+    seeds: Vec<T>,
+    category_maps: Vec<CategoryMap<T>>,
+    routes: Graph<String, u32>,
+}
+
+impl<T: Integer + FromStr> Almanac<T> {
+    pub fn parse(lines: Lines) -> Result<Self, ParseError> {
+        let blocks: Vec<(usize, Vec<&str>)> = split_into_numbered_blocks(lines);
+        let (seeds_line_nr, seeds_block) = blocks.first().ok_or(ParseError::MissingSeeds)?;
+        let seeds: Vec<T> = parse_seeds_line(seeds_block[0], *seeds_line_nr)?;
+        let mut category_maps: Vec<CategoryMap<T>> = Vec::with_capacity(blocks.len() - 1);
+        let mut header_line_nrs: HashMap<(String, String), usize> = HashMap::new();
+        for (line_nr, block) in blocks[1..].iter() {
+            let (source, destination) = parse_map_header(block[0], *line_nr)?;
+            if let Some(other_line_nr) = header_line_nrs.insert((source.clone(), destination.clone()), *line_nr) {
+                return Err(ParseError::DuplicateMap { line_nr: *line_nr, other_line_nr });
+            }
+            let multi_range_map = MultiRangeMap::parse(block, *line_nr)?;
+            category_maps.push(CategoryMap { source, destination, multi_range_map });
+        }
+
+        let mut routes: Graph<String, u32> = Graph::new();
+        for category_map in category_maps.iter() {
+            routes.add_edge(category_map.source.clone(), category_map.destination.clone(), 1);
+        }
+
+        Ok(Almanac { seeds, category_maps, routes })
+    }
+
+    pub fn seeds(&self) -> &[T] {
+        &self.seeds
+    }
+
+    /// The maps to apply, in order, to get from category `from` to category `to`.
+    pub fn route(&self, from: &str, to: &str) -> Result<Vec<&MultiRangeMap<T>>, AlmanacError<T>> {
+        let (_, categories) = self.routes
+            .shortest_path(&from.to_string(), &to.to_string())
+            .ok_or_else(|| AlmanacError::NoRoute { from: from.to_string(), to: to.to_string() })?;
+        let maps: Vec<&MultiRangeMap<T>> = categories
+            .windows(2)
+            .map(|pair| self.find_map(pair[0], pair[1]))
+            .collect();
+        Ok(maps)
+    }
+
+    pub fn map_value(&self, from: &str, to: &str, value: T) -> Result<T, AlmanacError<T>> {
+        let maps = self.route(from, to)?;
+        Ok(map_along(&maps, value))
+    }
+
+    /// Map all values, finding the route only once.
+    pub fn map_values(&self, from: &str, to: &str, values: &[T]) -> Result<Vec<T>, AlmanacError<T>> {
+        let maps = self.route(from, to)?;
+        Ok(values.iter().map(|value| map_along(&maps, value.clone())).collect())
+    }
+
+    pub fn map_ranges(&self, from: &str, to: &str, ranges: &RangeSet<T>) -> Result<RangeSet<T>, AlmanacError<T>> {
+        let maps = self.route(from, to)?;
+        Ok(maps.iter().fold(ranges.clone(), |acc, multi_range_map| multi_range_map.map_ranges(&acc)))
+    }
+
+    /// All maps on the route composed into one, mapping `from` straight to `to`.
+    pub fn composed_map(&self, from: &str, to: &str) -> Result<MultiRangeMap<T>, AlmanacError<T>> {
+        Ok(MultiRangeMap::compose_all(self.route(from, to)?))
+    }
+
+    /// The map from a location back to the seed that lands on it.
+    pub fn location_to_seed_map(&self) -> Result<MultiRangeMap<T>, AlmanacError<T>> {
+        self.composed_map("seed", "location")?
+            .inverse()
+            .map_err(AlmanacError::NotInjective)
+    }
+
+    fn find_map(&self, source: &str, destination: &str) -> &MultiRangeMap<T> {
+        self.category_maps
+            .iter()
+            .find(|category_map| category_map.source == source && category_map.destination == destination)
+            .map(|category_map| &category_map.multi_range_map)
+            .expect("Every edge in the routes comes from exactly one map, parse rejects duplicate headers")
+    }
+}
+
+fn map_along<T: Integer>(maps: &[&MultiRangeMap<T>], value: T) -> T {
+    maps.iter().fold(value, |acc, multi_range_map| multi_range_map.map(acc))
+}
+
+pub fn parse_seeds_line<T: FromStr>(line: &str, line_nr: usize) -> Result<Vec<T>, ParseError> {
+    let numbers = line
+        .strip_prefix("seeds:")
+        .ok_or(ParseError::MissingSeeds)?;
+    numbers.split_whitespace()
+        .map(|field| parse_number(field, line_nr))
+        .collect()
+}
+
+/// Get the categories from a header like `seed-to-soil map:`.
+fn parse_map_header(line: &str, line_nr: usize) -> Result<(String, String), ParseError> {
+    line.strip_suffix(" map:")
+        .and_then(|categories| categories.split_once("-to-"))
+        .map(|(source, destination)| (source.to_string(), destination.to_string()))
+        .ok_or_else(|| ParseError::InvalidHeader { line_nr, line: line.to_string() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Range;
+
+    static INPUT: &str = r#"seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+water-to-light map:
+88 18 7
+18 25 70
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4"#;
+
+    #[test]
+    fn test_parse_map_header() {
+        assert_eq!(parse_map_header("seed-to-soil map:", 3), Ok(("seed".to_string(), "soil".to_string())));
+        assert_eq!(parse_map_header("seed-soil map:", 3),
+                   Err(ParseError::InvalidHeader { line_nr: 3, line: "seed-soil map:".to_string() }));
+    }
+
+    #[test]
+    fn test_parse_error_line_nr() {
+        // Arrange
+        let input = INPUT.replace("37 52 2", "37 52 two");
+        let expected = ParseError::NotANumber { line_nr: 9, field: "two".to_string() };
+
+        // Act
+        let actual = Almanac::<i64>::parse(input.lines());
+
+        // Assert
+        assert_eq!(actual.err(), Some(expected));
+    }
+
+    #[test]
+    fn test_parse_without_seeds() {
+        assert_eq!(Almanac::<i64>::parse("seed-to-soil map:\n50 98 2".lines()).err(), Some(ParseError::MissingSeeds));
+        assert_eq!(Almanac::<i64>::parse("".lines()).err(), Some(ParseError::MissingSeeds));
+    }
+
+    #[test]
+    fn test_map_value_between_categories() {
+        run_map_value_test_case("seed", "soil", 79, Ok(81));
+        run_map_value_test_case("soil", "water", 81, Ok(81));
+        run_map_value_test_case("seed", "light", 14, Ok(42));
+        run_map_value_test_case("seed", "seed", 14, Ok(14));
+    }
+
+    #[test]
+    fn test_map_value_without_route() {
+        let expected = AlmanacError::NoRoute { from: "light".to_string(), to: "seed".to_string() };
+        run_map_value_test_case("light", "seed", 14, Err(expected));
+    }
+
+    #[test]
+    fn test_map_value_unknown_category() {
+        let expected = AlmanacError::NoRoute { from: "seed".to_string(), to: "location".to_string() };
+        run_map_value_test_case("seed", "location", 14, Err(expected));
+    }
+
+    fn run_map_value_test_case(from: &str, to: &str, value: i64, expected: Result<i64, AlmanacError<i64>>) {
+        // Arrange
+        let almanac = Almanac::<i64>::parse(INPUT.lines()).unwrap();
+
+        // Act
+        let actual = almanac.map_value(from, to, value);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_map_value_u128() {
+        // Arrange
+        let almanac = Almanac::<u128>::parse(INPUT.lines()).unwrap();
+
+        // Act
+        let actual = almanac.map_value("seed", "light", 14);
+
+        // Assert
+        assert_eq!(actual, Ok(42));
+    }
+
+    #[test]
+    fn test_parse_overflow() {
+        // Arrange
+        let input = INPUT.replace("37 52 2", "37 9223372036854775807 2");
+
+        // Act
+        let actual = Almanac::<i64>::parse(input.lines());
+
+        // Assert
+        assert_eq!(actual.err(), Some(ParseError::Overflow { line_nr: 9 }));
+    }
+
+    #[test]
+    fn test_map_ranges_between_categories() {
+        // Arrange
+        let almanac = Almanac::<i64>::parse(INPUT.lines()).unwrap();
+        let ranges: RangeSet<i64> = RangeSet::from_iter([Range::new(79, 14)]);
+
+        // Act
+        let actual = almanac.map_ranges("seed", "water", &ranges).unwrap();
+
+        // Assert
+        let expected: Vec<i64> = (79..93).map(|seed| almanac.map_value("seed", "water", seed).unwrap()).collect();
+        assert_eq!(actual.len(), Ok(14));
+        assert!(expected.iter().all(|water| actual.contains(*water)));
+    }
+
+    #[test]
+    fn test_parse_duplicate_map() {
+        // Arrange
+        let input = format!("{}\n\nseed-to-soil map:\n1 2 3", INPUT);
+        let expected = ParseError::DuplicateMap { line_nr: 22, other_line_nr: 3 };
+
+        // Act
+        let actual = Almanac::<i64>::parse(input.lines());
+
+        // Assert
+        assert_eq!(actual.err(), Some(expected));
+    }
+
+    #[test]
+    fn test_map_values() {
+        // Arrange
+        let almanac = Almanac::<i64>::parse(INPUT.lines()).unwrap();
+
+        // Act
+        let actual = almanac.map_values("seed", "light", &[79, 14]);
+
+        // Assert
+        assert_eq!(actual, Ok(vec![almanac.map_value("seed", "light", 79).unwrap(), 42]));
+    }
+}
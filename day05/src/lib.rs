@@ -0,0 +1,129 @@
+use std::str::Lines;
+use common::{Answer, Range, RangeSet, Solution};
+use crate::almanac::Almanac;
+
+mod almanac;
+mod parse_error;
+mod range_map;
+mod multi_range_map;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Almanac<i64>;
+
+    fn parse(lines: Lines<'_>) -> Self::Input<'_> {
+        Almanac::parse(lines).unwrap_or_else(|error| panic!("Invalid almanac: {}", error))
+    }
+
+    fn part1(almanac: &Self::Input<'_>) -> Answer {
+        almanac.map_values("seed", "location", almanac.seeds())
+            .unwrap()
+            .into_iter()
+            .min()
+            .expect("The almanac has no seeds")
+            .into()
+    }
+
+    fn part2(almanac: &Self::Input<'_>) -> Answer {
+        let seed_ranges: RangeSet<i64> = almanac.seeds()
+            .chunks(2)
+            .map(|chunk| Range::try_new(chunk[0], chunk[1])
+                .unwrap_or_else(|error| panic!("Seed range {} {} is too large: {}", chunk[0], chunk[1], error)))
+            .collect();
+        let seed_to_location = almanac.composed_map("seed", "location").unwrap();
+        let locations: RangeSet<i64> = seed_to_location.map_ranges(&seed_ranges);
+        locations.ranges()
+            .first()
+            .expect("The almanac has no seed ranges")
+            .start()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+"#;
+
+    #[test]
+    fn test_solve_part1()
+    {
+        // Arrange
+        let expected = Answer::from(35);
+
+        // Act
+        let actual: Answer = Day05::solve_part1(INPUT.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2()
+    {
+        // Arrange
+        let expected = Answer::from(46);
+
+        // Act
+        let actual: Answer = Day05::solve_part2(INPUT.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_location_to_seed() {
+        // Arrange
+        let almanac = Day05::parse(INPUT.lines());
+
+        // Act
+        let location_to_seed = almanac.location_to_seed_map().unwrap();
+
+        // Assert
+        assert_eq!(location_to_seed.map(46), 82);
+        assert_eq!(location_to_seed.map(35), 13);
+    }
+
+    #[test]
+    #[should_panic(expected = "The almanac has no seed ranges")]
+    fn test_solve_part2_without_seeds() {
+        Day05::solve_part2("seeds:\n\nseed-to-location map:\n50 98 2".lines());
+    }
+}
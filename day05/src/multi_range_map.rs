@@ -1,36 +1,91 @@
-use common::Range;
+use common::{Integer, Range, RangeSet};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use crate::parse_error::ParseError;
 use crate::range_map::RangeMap;
 
+/// Returned by `MultiRangeMap::inverse` when multiple numbers map onto the same number.
+#[derive(Debug, PartialEq)]
+pub enum NotInjectiveError<T: Integer> {
+    /// Two source ranges map onto these overlapping destination ranges.
+    OverlappingDestinations { first: Range<T>, second: Range<T> },
+    /// These destination numbers are not in any source range, so they also map onto themselves.
+    DestinationNotInSources(Range<T>),
+}
+
+impl<T: Integer> fmt::Display for NotInjectiveError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotInjectiveError::OverlappingDestinations { first, second } =>
+                write!(f, "Destination ranges {:?} and {:?} overlap", first, second),
+            NotInjectiveError::DestinationNotInSources(range) =>
+                write!(f, "Destination range {:?} is not in any source range, so it is mapped onto twice", range),
+        }
+    }
+}
+
+impl<T: Integer> Error for NotInjectiveError<T> {}
+
 /// MultiRange map is a map that is able to map numbers and ranges from multiple disjoint ranges
 /// to new numbers and ranges.
 #[derive(Debug, PartialEq)]
-pub struct MultiRangeMap {
-    range_maps: Vec<RangeMap>
+pub struct MultiRangeMap<T: Integer> {
+    range_maps: Vec<RangeMap<T>>
 }
 
-impl MultiRangeMap {
-    pub fn parse(lines: &Vec<&str>) -> Self {
-        let mut range_maps: Vec<RangeMap> = lines[1..]
-            .iter()
-            .map(|line| RangeMap::parse(line))
-            .collect();
-
+impl<T: Integer> MultiRangeMap<T> {
+    /// Create the map from range maps with disjoint sources.
+    pub fn new(mut range_maps: Vec<RangeMap<T>>) -> Self {
         // Sort the maps on source so the implementation of map_range can be simpler.
-        range_maps.sort_by(|a, b| a.source().start().cmp(&b.source().start()));
+        range_maps.sort_by_key(|a| a.source().start());
 
         MultiRangeMap { range_maps }
     }
 
-    pub fn map(&self, source: i64) -> i64 {
+    /// Parse a block of the almanac: a header line followed by a line per range map.
+    /// `first_line_nr` is the line number of the header, it is only used in errors.
+    pub fn parse(lines: &[&str], first_line_nr: usize) -> Result<Self, ParseError>
+    where
+        T: FromStr,
+    {
+        let mut numbered_range_maps: Vec<(usize, RangeMap<T>)> = lines[1..]
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                let line_nr = first_line_nr + 1 + index;
+                RangeMap::parse(line, line_nr).map(|range_map| (line_nr, range_map))
+            })
+            .collect::<Result<_, _>>()?;
+
+        // map_range relies on the sources being disjoint.
+        numbered_range_maps.sort_by_key(|(_, range_map)| range_map.source().start());
+        if let Some(pair) = numbered_range_maps.windows(2).find(|pair| pair[0].1.source().overlaps(&pair[1].1.source())) {
+            let (first_line_nr, second_line_nr) = (pair[0].0, pair[1].0);
+            return Err(ParseError::OverlappingSources {
+                line_nr: first_line_nr.max(second_line_nr),
+                other_line_nr: first_line_nr.min(second_line_nr),
+            });
+        }
+
+        Ok(MultiRangeMap::new(numbered_range_maps.into_iter().map(|(_, range_map)| range_map).collect()))
+    }
+
+    /// The range maps, sorted on source.
+    pub fn range_maps(&self) -> &[RangeMap<T>] {
+        &self.range_maps
+    }
+
+    pub fn map(&self, source: T) -> T {
         self.range_maps
             .iter()
-            .filter(|range_map| range_map.contains(source))
+            .filter(|range_map| range_map.contains(source.clone()))
             .nth(0)
-            .map_or(source, |range_map| range_map.map(source))
+            .map_or(source.clone(), |range_map| range_map.map(source))
     }
 
-    pub fn map_range(&self, source: Range<i64>) -> Vec<Range<i64>> {
-        let mut result: Vec<Range<i64>> = Vec::new();
+    pub fn map_range(&self, source: Range<T>) -> Vec<Range<T>> {
+        let mut result: Vec<Range<T>> = Vec::new();
 
         // Start with the full `self` range
         let mut current_source_range = source;
@@ -57,32 +112,104 @@ impl MultiRangeMap {
             // Since there's no overlap, this part doesn't need to be mapped.
             if current_source_range.start() < overlap_start {
                 result.push(Range::new(current_source_range.start(),
-                                       overlap_start - current_source_range.start()));
+                                       overlap_start.clone() - current_source_range.start()));
             }
 
             // Map and add the overlapping part
-            let overlapping_range = Range::new(overlap_start, overlap_end - overlap_start);
+            let overlapping_range = Range::new(overlap_start.clone(), overlap_end.clone() - overlap_start);
             let map_result= range_map.map_range(overlapping_range);
             assert_eq!(map_result.len(), 1);
-            result.push(map_result[0]);
+            result.push(map_result[0].clone());
 
             // Update the current range to the remaining part after the overlap
-            current_source_range = Range::new(overlap_end, current_source_range.end() - overlap_end);
+            current_source_range = Range::new(overlap_end.clone(), current_source_range.end() - overlap_end);
         }
 
         // Add the remaining part of `self` after processing all ranges, this is not overlapping
         // with any range map, so we don't need to map.
-        if current_source_range.length() > 0 {
+        if current_source_range.length() > T::default() {
             result.push(current_source_range);
         }
 
         result
     }
 
-    pub fn map_ranges(&self, ranges_to_map: Vec<Range<i64>>) -> Vec<Range<i64>> {
-        ranges_to_map.iter()
-            .map(|range_to_map| self.map_range(*range_to_map))
-            .flatten()
+    /// Create the single map that gives the same result as mapping with `self` first and `next`
+    /// after that. Parts that end up unchanged are left out, like they are in the input.
+    pub fn compose(&self, next: &MultiRangeMap<T>) -> MultiRangeMap<T> {
+        let sources: RangeSet<T> = self.range_maps.iter().map(|range_map| range_map.source()).collect();
+        let next_sources: RangeSet<T> = next.range_maps.iter().map(|range_map| range_map.source()).collect();
+        let mut range_maps: Vec<RangeMap<T>> = Vec::new();
+
+        for range_map in self.range_maps.iter() {
+            let destination = range_map.destination();
+            let back = range_map.inverse();
+            // The parts that `next` maps again.
+            for next_range_map in next.range_maps.iter() {
+                if let Some(overlap) = destination.intersection(&next_range_map.source()) {
+                    let source = Range::new(back.map(overlap.start()), overlap.length());
+                    range_maps.push(RangeMap::new(source, next_range_map.map(overlap.start())));
+                }
+            }
+            // The parts that `next` leaves unchanged.
+            for range in RangeSet::from_iter([destination]).difference(&next_sources).ranges() {
+                range_maps.push(RangeMap::new(Range::new(back.map(range.start()), range.length()), range.start()));
+            }
+        }
+
+        // Numbers that `self` leaves unchanged are only mapped by `next`.
+        for next_range_map in next.range_maps.iter() {
+            let unmapped = RangeSet::from_iter([next_range_map.source()]).difference(&sources);
+            for range in unmapped.ranges() {
+                range_maps.push(RangeMap::new(range.clone(), next_range_map.map(range.start())));
+            }
+        }
+
+        range_maps.retain(|range_map| range_map.source() != range_map.destination());
+        MultiRangeMap::new(range_maps)
+    }
+
+    /// Compose all maps into a single one, in order. No maps gives the map that changes nothing.
+    pub fn compose_all<'a>(multi_range_maps: impl IntoIterator<Item = &'a MultiRangeMap<T>>) -> MultiRangeMap<T>
+    where
+        T: 'a,
+    {
+        multi_range_maps.into_iter()
+            .fold(MultiRangeMap::new(Vec::new()), |composed, multi_range_map| composed.compose(multi_range_map))
+    }
+
+    /// Create the map that takes every destination number back to the source number it came from.
+    /// Fails when two numbers are mapped onto the same number, since then there's no way back.
+    pub fn inverse(&self) -> Result<MultiRangeMap<T>, NotInjectiveError<T>> {
+        let mut inverted: Vec<RangeMap<T>> = self.range_maps.iter()
+            .filter(|range_map| !range_map.source().is_empty())
+            .map(|range_map| range_map.inverse())
+            .collect();
+        inverted.sort_by_key(|range_map| range_map.source().start());
+
+        // Sorted on start, so a range can only overlap a later range if it overlaps the next one.
+        if let Some(pair) = inverted.windows(2).find(|pair| pair[0].source().overlaps(&pair[1].source())) {
+            return Err(NotInjectiveError::OverlappingDestinations {
+                first: pair[0].source(),
+                second: pair[1].source(),
+            });
+        }
+
+        // Numbers outside the sources map onto themselves, so no destination may land there.
+        let sources: RangeSet<T> = self.range_maps.iter().map(|range_map| range_map.source()).collect();
+        let destinations: RangeSet<T> = inverted.iter().map(|range_map| range_map.source()).collect();
+        if let Some(range) = destinations.difference(&sources).ranges().first() {
+            return Err(NotInjectiveError::DestinationNotInSources(range.clone()));
+        }
+
+        Ok(MultiRangeMap::new(inverted))
+    }
+
+    /// Map all ranges in the set, the mapped ranges are merged again where they touch.
+    pub fn map_ranges(&self, ranges_to_map: &RangeSet<T>) -> RangeSet<T> {
+        ranges_to_map.ranges()
+            .iter()
+            .flat_map(|range_to_map| self.map_range(range_to_map.clone()))
             .collect()
     }
 }
@@ -90,6 +217,7 @@ impl MultiRangeMap {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use num_bigint::BigInt;
 
     #[test]
     fn test_map_source_in_first_range() {
@@ -111,7 +239,7 @@ mod tests {
         let lines = r#"seed-to-soil map:
 50 98 2
 52 50 48"#;
-        let multi_range_map: MultiRangeMap = MultiRangeMap::parse(&lines.lines().collect());
+        let multi_range_map: MultiRangeMap<i64> = MultiRangeMap::parse(&lines.lines().collect::<Vec<&str>>(), 1).unwrap();
 
         // Act
         let actual = multi_range_map.map(source);
@@ -128,7 +256,7 @@ mod tests {
 45 77 23
 81 45 19
 68 64 13"#;
-        let multi_range_map = MultiRangeMap::parse(&multi_range_map_text.lines().collect());
+        let multi_range_map: MultiRangeMap<i64> = MultiRangeMap::parse(&multi_range_map_text.lines().collect::<Vec<&str>>(), 1).unwrap();
         let expected = vec![
             Range::new(78, 3),
             Range::new(45, 11)
@@ -140,4 +268,135 @@ mod tests {
         // Assert
         assert_eq!(actual, expected);
     }
-}
\ No newline at end of file
+
+    fn parse_multi_range_map(text: &str) -> MultiRangeMap<i64> {
+        MultiRangeMap::parse(&text.lines().collect::<Vec<&str>>(), 1).unwrap()
+    }
+
+    #[test]
+    fn test_compose() {
+        // Arrange
+        let first = parse_multi_range_map("seed-to-soil map:\n50 98 2\n52 50 48");
+        let second = parse_multi_range_map("soil-to-fertilizer map:\n0 15 37\n37 52 2\n39 0 15");
+
+        // Act
+        let composed = first.compose(&second);
+
+        // Assert
+        for source in -10..120 {
+            assert_eq!(composed.map(source), second.map(first.map(source)), "mapping {}", source);
+        }
+        let sources: Vec<Range<i64>> = composed.range_maps().iter().map(|range_map| range_map.source()).collect();
+        assert!(sources.windows(2).all(|pair| pair[0].end() <= pair[1].start()));
+    }
+
+    #[test]
+    fn test_compose_u128() {
+        // Arrange
+        let first: MultiRangeMap<u128> = MultiRangeMap::parse(&["a-to-b map:", "0 340282366920938463463374607431768211400 50"], 1).unwrap();
+        let second: MultiRangeMap<u128> = MultiRangeMap::parse(&["b-to-c map:", "100 10 20"], 3).unwrap();
+
+        // Act
+        let composed = first.compose(&second);
+
+        // Assert
+        assert_eq!(composed.map(340_282_366_920_938_463_463_374_607_431_768_211_415), 105);
+        assert_eq!(composed.map(340_282_366_920_938_463_463_374_607_431_768_211_440), 40);
+        assert_eq!(composed.map(15), 105);
+        assert_eq!(composed.map(40), 40);
+    }
+
+    #[test]
+    fn test_map_range_big_int() {
+        // Arrange
+        let lines = ["a-to-b map:", "5 100000000000000000000000000000000000000000 10"];
+        let multi_range_map: MultiRangeMap<BigInt> = MultiRangeMap::parse(&lines, 1).unwrap();
+        let start: BigInt = "99999999999999999999999999999999999999995".parse().unwrap();
+        let expected = vec![Range::new(start.clone(), BigInt::from(5)), Range::new(BigInt::from(5), BigInt::from(3))];
+
+        // Act
+        let actual = multi_range_map.map_range(Range::new(start, BigInt::from(8)));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_compose_all_without_maps() {
+        // Arrange
+        let maps: Vec<MultiRangeMap<i64>> = Vec::new();
+
+        // Act
+        let composed = MultiRangeMap::compose_all(&maps);
+
+        // Assert
+        assert_eq!(composed.range_maps(), &[]);
+        assert_eq!(composed.map(42), 42);
+    }
+
+    #[test]
+    fn test_inverse() {
+        // Arrange
+        let multi_range_map = parse_multi_range_map("soil-to-fertilizer map:\n0 15 37\n37 52 2\n39 0 15");
+
+        // Act
+        let inverse = multi_range_map.inverse().unwrap();
+
+        // Assert
+        for source in -10..70 {
+            assert_eq!(inverse.map(multi_range_map.map(source)), source, "mapping {}", source);
+        }
+    }
+
+    #[test]
+    fn test_inverse_overlapping_destinations() {
+        // Arrange
+        let multi_range_map = parse_multi_range_map("a-to-b map:\n10 0 5\n12 5 5");
+        let expected = NotInjectiveError::OverlappingDestinations { first: Range::new(10, 5), second: Range::new(12, 5) };
+
+        // Act
+        let actual = multi_range_map.inverse();
+
+        // Assert
+        assert_eq!(actual, Err(expected));
+    }
+
+    #[test]
+    fn test_inverse_destination_not_in_sources() {
+        // Arrange
+        let multi_range_map = parse_multi_range_map("a-to-b map:\n8 0 5");
+        let expected = NotInjectiveError::DestinationNotInSources(Range::new(8, 5));
+
+        // Act
+        let actual = multi_range_map.inverse();
+
+        // Assert
+        assert_eq!(actual, Err(expected));
+    }
+
+    #[test]
+    fn test_parse_overlapping_sources() {
+        // Arrange
+        let lines = ["a-to-b map:", "0 10 5", "20 30 5", "40 12 2"];
+        let expected = ParseError::OverlappingSources { line_nr: 13, other_line_nr: 11 };
+
+        // Act
+        let actual = MultiRangeMap::<i64>::parse(&lines, 10);
+
+        // Assert
+        assert_eq!(actual, Err(expected));
+    }
+
+    #[test]
+    fn test_parse_invalid_line() {
+        // Arrange
+        let lines = ["a-to-b map:", "0 10 5", "20 30"];
+        let expected = ParseError::WrongFieldCount { line_nr: 3, count: 2 };
+
+        // Act
+        let actual = MultiRangeMap::<i64>::parse(&lines, 1);
+
+        // Assert
+        assert_eq!(actual, Err(expected));
+    }
+}
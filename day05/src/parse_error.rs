@@ -0,0 +1,47 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Everything that can be wrong with the almanac text. Line numbers start at 1.
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    MissingSeeds,
+    InvalidHeader { line_nr: usize, line: String },
+    NotANumber { line_nr: usize, field: String },
+    WrongFieldCount { line_nr: usize, count: usize },
+    NegativeLength { line_nr: usize, length: String },
+    /// A range on this line doesn't fit in the number type.
+    Overflow { line_nr: usize },
+    OverlappingSources { line_nr: usize, other_line_nr: usize },
+    /// A map with the same categories was already given on another line.
+    DuplicateMap { line_nr: usize, other_line_nr: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingSeeds =>
+                write!(f, "The almanac doesn't start with a seeds line"),
+            ParseError::InvalidHeader { line_nr, line } =>
+                write!(f, "Line {}: expected a header like 'seed-to-soil map:', got '{}'", line_nr, line),
+            ParseError::NotANumber { line_nr, field } =>
+                write!(f, "Line {}: '{}' is not a number", line_nr, field),
+            ParseError::WrongFieldCount { line_nr, count } =>
+                write!(f, "Line {}: expected 3 numbers, got {}", line_nr, count),
+            ParseError::NegativeLength { line_nr, length } =>
+                write!(f, "Line {}: the length {} is negative", line_nr, length),
+            ParseError::Overflow { line_nr } =>
+                write!(f, "Line {}: the range is too large for the number type", line_nr),
+            ParseError::OverlappingSources { line_nr, other_line_nr } =>
+                write!(f, "Line {}: the source range overlaps the one on line {}", line_nr, other_line_nr),
+            ParseError::DuplicateMap { line_nr, other_line_nr } =>
+                write!(f, "Line {}: the same map is already given on line {}", line_nr, other_line_nr),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn parse_number<T: FromStr>(field: &str, line_nr: usize) -> Result<T, ParseError> {
+    field.parse().map_err(|_| ParseError::NotANumber { line_nr, field: field.to_string() })
+}
@@ -1,64 +1,91 @@
 use std::fmt;
-use common::Range;
+use std::str::FromStr;
+use common::{Integer, Range};
+use crate::parse_error::{parse_number, ParseError};
 
 /// RangeMap maps a source number to a destination number:
 /// if the source number falls within the range, it is mapped to the respective
 /// destination number,
 /// if the source number falls outside the range, it is unchanged.
-#[derive(PartialEq)]
-pub struct RangeMap {
-    source: Range<i64>,
-    dest_start_index: i64
+#[derive(PartialEq, Clone, Copy)]
+pub struct RangeMap<T: Integer> {
+    source: Range<T>,
+    dest_start_index: T
 }
 
-impl fmt::Debug for RangeMap {
+impl<T: Integer> fmt::Debug for RangeMap<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}->{:?}", self.source, self.dest_start_index)
     }
 }
 
-impl RangeMap {
-    pub fn parse(line: &str) -> Self {
-        let numbers: Vec<i64> = line
-            .split(' ')
-            .map(|number_text| number_text.parse::<i64>().unwrap())
-            .collect();
+impl<T: Integer> RangeMap<T> {
+    pub fn new(source: Range<T>, dest_start_index: T) -> Self {
+        RangeMap { source, dest_start_index }
+    }
 
-        let dest_start_index = numbers[0];
-        let source_start_index = numbers[1];
-        let source_length = numbers[2];
+    /// Parse a line with the destination start, source start and length. `line_nr` is only used
+    /// in the error.
+    pub fn parse(line: &str, line_nr: usize) -> Result<Self, ParseError>
+    where
+        T: FromStr,
+    {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() != 3 {
+            return Err(ParseError::WrongFieldCount { line_nr, count: fields.len() });
+        }
+        let numbers: Vec<T> = fields
+            .iter()
+            .map(|field| parse_number(field, line_nr))
+            .collect::<Result<_, _>>()?;
 
-        let source = Range::new(source_start_index, source_length);
-        RangeMap { source, dest_start_index }
+        let [dest_start_index, source_start_index, source_length]: [T; 3] = numbers.try_into().unwrap();
+        if source_length < T::default() {
+            return Err(ParseError::NegativeLength { line_nr, length: fields[2].to_string() });
+        }
+
+        // Both ranges have to fit in `T`, so mapping a number can't overflow later on.
+        let source = Range::try_new(source_start_index, source_length.clone())
+            .map_err(|_| ParseError::Overflow { line_nr })?;
+        Range::try_new(dest_start_index.clone(), source_length)
+            .map_err(|_| ParseError::Overflow { line_nr })?;
+
+        Ok(RangeMap::new(source, dest_start_index))
     }
 
-    pub fn source(&self) -> Range<i64> {
-        self.source
+    pub fn source(&self) -> Range<T> {
+        self.source.clone()
     }
 
-    pub fn contains(&self, n: i64) -> bool {
+    pub fn destination(&self) -> Range<T> {
+        Range::new(self.dest_start_index.clone(), self.source.length())
+    }
+
+    pub fn contains(&self, n: T) -> bool {
         self.source.contains(n)
     }
 
-    pub fn map(&self, source: i64) -> i64 {
-        if self.contains(source) {
-            self.dest_start_index + source - self.source.start()
+    pub fn map(&self, source: T) -> T {
+        if self.contains(source.clone()) {
+            // Subtract first, so unsigned types don't go below zero.
+            self.dest_start_index.clone() + (source - self.source.start())
         }
         else {
             source
         }
     }
 
-    pub fn map_range(&self, source: Range<i64>) -> Vec<Range<i64>> {
-        let ranges: Vec<Range<i64>> = source.split(&self.source);
+    pub fn map_range(&self, source: Range<T>) -> Vec<Range<T>> {
+        let ranges: Vec<Range<T>> = source.split(&self.source);
         ranges
-            .iter()
-            .map(|range| if self.source.overlaps(range) { range.shifted(self.delta()) } else { *range })
+            .into_iter()
+            .map(|range| if self.source.overlaps(&range) { Range::new(self.map(range.start()), range.length()) } else { range })
             .collect()
     }
 
-    fn delta(&self) -> i64 {
-        self.dest_start_index as i64 - self.source.start() as i64
+    /// The map that takes the destination range back to the source range.
+    pub fn inverse(&self) -> RangeMap<T> {
+        RangeMap::new(self.destination(), self.source.start())
     }
 }
 
@@ -74,10 +101,52 @@ mod tests {
         let expected = RangeMap { source, dest_start_index: 50 };
 
         // Act
-        let actual = RangeMap::parse(line);
+        let actual = RangeMap::parse(line, 1).unwrap();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(RangeMap::<i64>::parse("50 98", 3), Err(ParseError::WrongFieldCount { line_nr: 3, count: 2 }));
+        assert_eq!(RangeMap::<i64>::parse("50 98 2 1", 3), Err(ParseError::WrongFieldCount { line_nr: 3, count: 4 }));
+        assert_eq!(RangeMap::<i64>::parse("50 x8 2", 4), Err(ParseError::NotANumber { line_nr: 4, field: "x8".to_string() }));
+        assert_eq!(RangeMap::<i64>::parse("50 98 -2", 5), Err(ParseError::NegativeLength { line_nr: 5, length: "-2".to_string() }));
+    }
+
+    #[test]
+    fn test_parse_overflow() {
+        assert_eq!(RangeMap::<i64>::parse("0 9223372036854775807 2", 6), Err(ParseError::Overflow { line_nr: 6 }));
+        assert_eq!(RangeMap::<i64>::parse("9223372036854775807 0 2", 7), Err(ParseError::Overflow { line_nr: 7 }));
+        assert_eq!(RangeMap::<u64>::parse("0 18446744073709551615 2", 8), Err(ParseError::Overflow { line_nr: 8 }));
+    }
+
+    #[test]
+    fn test_map_u128_beyond_i64() {
+        // Arrange
+        let range_map: RangeMap<u128> = RangeMap::parse("10 340282366920938463463374607431768211400 50", 1).unwrap();
+
+        // Act
+        let actual = range_map.map(340_282_366_920_938_463_463_374_607_431_768_211_420);
+
+        // Assert
+        assert_eq!(actual, 30);
+        assert_eq!(range_map.inverse().map(actual), 340_282_366_920_938_463_463_374_607_431_768_211_420);
+    }
+
+    #[test]
+    fn test_inverse() {
+        // Arrange
+        let range_map: RangeMap<i64> = RangeMap::parse("50 98 2", 1).unwrap();
+        let expected = RangeMap::parse("98 50 2", 1).unwrap();
+
+        // Act
+        let actual = range_map.inverse();
 
         // Assert
         assert_eq!(actual, expected);
+        assert_eq!(actual.map(range_map.map(99)), 99);
     }
 
     #[test]
@@ -92,7 +161,7 @@ mod tests {
 
     fn run_map_test_case(source: i64, expected: i64) {
         // Arrange
-        let range_map = RangeMap::parse("50 98 2");
+        let range_map: RangeMap<i64> = RangeMap::parse("50 98 2", 1).unwrap();
 
         // Act
         let actual = range_map.map(source);
@@ -141,7 +210,7 @@ mod tests {
 
     fn run_map_range_test_case(source_start: i64, source_length: i64, expected: Vec<Range<i64>>) {
         // Arrange
-        let range_map = RangeMap::parse("50 98 2");
+        let range_map: RangeMap<i64> = RangeMap::parse("50 98 2", 1).unwrap();
         let source = Range::new(source_start, source_length);
 
         // Act
@@ -0,0 +1,110 @@
+mod race;
+
+use crate::race::Race;
+use common::{parse_numbers_from_string, Answer, Integer, Solution};
+use num_bigint::BigInt;
+use std::str::{FromStr, Lines};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    /// Both parts read the same two lines in a different way, so parsing is left to the parts.
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(lines: Lines<'_>) -> Self::Input<'_> {
+        lines.collect()
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> Answer {
+        parse_races_info(lines).iter()
+            .map(get_nr_options_that_beat_record)
+            .try_fold(1i64, |product, nr_options| product.try_mul(&nr_options))
+            .unwrap_or_else(|error| panic!("The product of the options doesn't fit in an i64: {}", error))
+            .into()
+    }
+
+    /// Without the spaces the numbers can get arbitrarily long, so they're read as a `BigInt`.
+    fn part2(lines: &Self::Input<'_>) -> Answer {
+        let duration: BigInt = get_deflated_number(lines[0]);
+        let milliseconds: BigInt = get_deflated_number(lines[1]);
+        let race = Race::new(duration, milliseconds);
+        get_nr_options_that_beat_record(&race).into()
+    }
+}
+
+fn get_nr_options_that_beat_record<T: Integer>(race: &Race<T>) -> T {
+    if cfg!(feature = "cross-check") {
+        race.get_nr_options_that_beat_record_cross_checked()
+            .unwrap_or_else(|error| panic!("{}", error))
+    } else {
+        race.get_nr_options_that_beat_record()
+    }
+}
+
+fn get_deflated_number<T: FromStr>(s: &str) -> T {
+    let number = s
+        .replace(" ", "")
+        .split(':')
+        .nth(1).unwrap()
+        .to_string();
+    number.parse::<T>()
+        .unwrap_or_else(|_| panic!("'{}' is not a number", number))
+}
+
+fn parse_races_info(lines: &[&str]) -> Vec<Race<i64>> {
+    // Time:      7  15   30
+    // Distance:  9  40  200
+    let durations_text: &str = lines[0]
+        .split(':')
+        .nth(1).unwrap()
+        .trim();
+    let durations: Vec<i64> = parse_numbers_from_string(durations_text);
+    let records_text: &str = lines[1]
+        .split(':')
+        .nth(1).unwrap()
+        .trim();
+    let records: Vec<i64> = parse_numbers_from_string(records_text);
+    assert_eq!(durations.len(), records.len());
+
+    let mut races: Vec<Race<i64>> = Vec::with_capacity(durations.len());
+    for i in 0..durations.len() {
+        races.push(Race::new(durations[i], records[i]));
+    }
+    races
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+
+    #[test]
+    fn test_solve_part1()
+    {
+        // Arrange
+        let expected = Answer::from(288);
+
+        // Act
+        let actual: Answer = Day06::solve_part1(INPUT.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2()
+    {
+        // Arrange
+        let expected = Answer::from(71503);
+
+        // Act
+        let actual: Answer = Day06::solve_part2(INPUT.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,29 +1,150 @@
-pub struct Race {
-    milliseconds: i64,
-    record: i64
+use common::{Integer, OverflowError};
+use std::error::Error;
+use std::fmt;
+
+pub struct Race<T: Integer> {
+    milliseconds: T,
+    record: T
+}
+
+/// Everything that can go wrong while counting the options of a race.
+#[derive(Debug, PartialEq)]
+pub enum RaceError<T: Integer> {
+    /// A distance while trying every hold time doesn't fit in the integer type of the race.
+    Overflow(OverflowError),
+    /// The closed-form count and the brute-force count differ.
+    Mismatch { closed_form: T, brute_force: T },
+}
+
+impl<T: Integer> fmt::Display for RaceError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RaceError::Overflow(error) =>
+                write!(f, "The race is too long for the number type: {}", error),
+            RaceError::Mismatch { closed_form, brute_force } =>
+                write!(f, "The closed form found {:?} options, trying all hold times found {:?}",
+                       closed_form, brute_force),
+        }
+    }
 }
 
-impl Race {
-    pub fn new(milliseconds: i64, record: i64) -> Race {
+impl<T: Integer> Error for RaceError<T> {}
+
+impl<T: Integer> From<OverflowError> for RaceError<T> {
+    fn from(error: OverflowError) -> Self {
+        RaceError::Overflow(error)
+    }
+}
+
+impl<T: Integer> Race<T> {
+    pub fn new(milliseconds: T, record: T) -> Race<T> {
         Race { milliseconds, record }
     }
 
-    pub fn get_nr_options_that_beat_record(&self) -> i64 {
-        (1..self.milliseconds)
-            .map(|i| self.get_distance_when_waiting(i))
-            .filter(|n| *n > self.record)
-            .count() as i64
+    /// Count the hold times `t` in `1..milliseconds` for which the distance `t * (milliseconds - t)`
+    /// beats the record. The distance is a parabola, so these are the integers between its
+    /// intersections with the record: `(milliseconds ± sqrt(milliseconds² - 4 * record)) / 2`.
+    /// The count always fits in `T`, even when `milliseconds²` doesn't.
+    pub fn get_nr_options_that_beat_record(&self) -> T {
+        let (one, two) = (T::from(1), T::from(2));
+        let half = self.milliseconds.clone() / two.clone();
+        if half < one || !self.beats_record(&half) {
+            return T::default(); // The parabola never gets above the record.
+        }
+
+        let first = match self.estimate_first_winning_hold_time() {
+            Some(estimate) => {
+                // The integer square root rounds down, so the estimate can be off by one. Step to the
+                // first hold time that beats the record, which also handles exact (perfect square) roots.
+                let mut first = estimate;
+                while first > one && self.beats_record(&(first.clone() - one.clone())) {
+                    first = first - one.clone();
+                }
+                while !self.beats_record(&first) {
+                    first = first + one.clone();
+                }
+                first
+            }
+            None => self.search_first_winning_hold_time(&half),
+        };
+
+        // The parabola is symmetric, so the last hold time mirrors the first one.
+        let last = self.milliseconds.clone() - first.clone();
+        last - first + one
+    }
+
+    /// The first hold time that beats the record according to the closed form, None when
+    /// `milliseconds²` doesn't fit in `T`.
+    fn estimate_first_winning_hold_time(&self) -> Option<T> {
+        let milliseconds = &self.milliseconds;
+        let four_records = T::from(4).try_mul(&self.record).ok()?;
+        let discriminant = milliseconds.try_mul(milliseconds).ok()?.try_sub(&four_records).ok()?;
+        let root = discriminant.integer_sqrt();
+        if root >= *milliseconds {
+            return Some(T::from(1));
+        }
+        Some(((milliseconds.clone() - root) / T::from(2)).max(T::from(1)))
+    }
+
+    /// Binary search for the first hold time that beats the record, for races that are too long
+    /// for the closed form. `half` has to beat the record.
+    fn search_first_winning_hold_time(&self, half: &T) -> T {
+        let (mut low, mut high) = (T::from(1), half.clone());
+        while low < high {
+            let middle = low.clone() + (high.clone() - low.clone()) / T::from(2);
+            if self.beats_record(&middle) {
+                high = middle;
+            } else {
+                low = middle + T::from(1);
+            }
+        }
+        low
+    }
+
+    /// Check if `hold_time`, between 1 and half the race, beats the record. Compares
+    /// `hold_time * (milliseconds - hold_time) > record` with a division, so it can't overflow.
+    fn beats_record(&self, hold_time: &T) -> bool {
+        if self.record < T::default() {
+            return true;
+        }
+        self.milliseconds.clone() - hold_time.clone() > self.record.clone() / hold_time.clone()
+    }
+
+    /// Count the options by trying every hold time, only feasible for short races.
+    pub fn get_nr_options_that_beat_record_brute_force(&self) -> Result<T, OverflowError> {
+        let one = T::from(1);
+        let mut count = T::default();
+        let mut hold_time = one.clone();
+        while hold_time < self.milliseconds {
+            if self.get_distance_when_waiting(&hold_time)? > self.record {
+                count = count + one.clone();
+            }
+            hold_time = hold_time + one.clone();
+        }
+        Ok(count)
+    }
+
+    /// Count the options both ways, to check the closed form against trying every hold time.
+    pub fn get_nr_options_that_beat_record_cross_checked(&self) -> Result<T, RaceError<T>> {
+        let closed_form = self.get_nr_options_that_beat_record();
+        let brute_force = self.get_nr_options_that_beat_record_brute_force()?;
+        if closed_form == brute_force {
+            Ok(closed_form)
+        } else {
+            Err(RaceError::Mismatch { closed_form, brute_force })
+        }
     }
 
-    fn get_distance_when_waiting(&self, milliseconds: i64) -> i64 {
+    fn get_distance_when_waiting(&self, milliseconds: &T) -> Result<T, OverflowError> {
         let speed = milliseconds;
-        (self.milliseconds - milliseconds) * speed
+        self.milliseconds.try_sub(milliseconds)?.try_mul(speed)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::race::Race;
+    use num_bigint::BigInt;
 
     #[test]
     fn test_get_nr_options_that_beat_record() {
@@ -37,4 +158,79 @@ mod tests {
         // Assert
         assert_eq!(actual, expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_get_nr_options_perfect_square_boundary() {
+        // Holding 10 or 20 ms exactly matches the record, so only 11..=19 beat it.
+        run_get_nr_options_test_case(30, 200, 9);
+    }
+
+    #[test]
+    fn test_get_nr_options_record_out_of_reach() {
+        run_get_nr_options_test_case(7, 13, 0);
+        run_get_nr_options_test_case(7, 12, 0);
+        run_get_nr_options_test_case(0, 0, 0);
+    }
+
+    #[test]
+    fn test_get_nr_options_large_race() {
+        // The distance for the best hold time is about 2^124, far beyond an i64.
+        run_get_nr_options_test_case(i64::MAX, 0, i64::MAX - 1);
+        run_get_nr_options_test_case(i64::MAX, i64::MAX, i64::MAX - 3);
+    }
+
+    fn run_get_nr_options_test_case(milliseconds: i64, record: i64, expected: i64) {
+        // Arrange
+        let race = Race::new(milliseconds, record);
+
+        // Act
+        let actual = race.get_nr_options_that_beat_record();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_get_nr_options_unsigned() {
+        assert_eq!(Race::new(0u64, 0).get_nr_options_that_beat_record(), 0);
+        assert_eq!(Race::new(1u64, 0).get_nr_options_that_beat_record(), 0);
+        assert_eq!(Race::new(u128::MAX, 0).get_nr_options_that_beat_record(), u128::MAX - 1);
+        assert_eq!(Race::new(u128::MAX, u128::MAX).get_nr_options_that_beat_record(), u128::MAX - 3);
+    }
+
+    #[test]
+    fn test_get_nr_options_big_int() {
+        // Arrange
+        let milliseconds: BigInt = BigInt::from(u128::MAX) * 1000;
+        let race = Race::new(milliseconds.clone(), BigInt::from(0));
+
+        // Act
+        let actual = race.get_nr_options_that_beat_record();
+
+        // Assert
+        assert_eq!(actual, milliseconds - 1);
+    }
+
+    #[test]
+    fn test_get_nr_options_without_closed_form() {
+        // Squaring overflows a u8 from 16 ms on, then the first winning hold time is searched for.
+        for milliseconds in 0..=u8::MAX {
+            for record in 0..=u8::MAX {
+                let expected = Race::new(milliseconds as i64, record as i64).get_nr_options_that_beat_record();
+                let actual = Race::new(milliseconds, record).get_nr_options_that_beat_record();
+                assert_eq!(actual as i64, expected, "race of {} ms with record {}", milliseconds, record);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cross_check_against_brute_force() {
+        for milliseconds in 0..60 {
+            for record in -5..(milliseconds * milliseconds / 4 + 3) {
+                let race = Race::new(milliseconds, record);
+                assert!(race.get_nr_options_that_beat_record_cross_checked().is_ok(),
+                        "race of {} ms with record {}", milliseconds, record);
+            }
+        }
+    }
+}
@@ -52,7 +52,7 @@ impl HandCategorizer for JokerHandCategorizer {
             .filter(|char_freq| char_freq.get_char() != 'J')
             .collect();
 
-        let top1_count = frequencies.get(0)
+        let top1_count = frequencies.first()
             .map(|char_freq| char_freq.get_frequency())
             .unwrap_or(0);
 
@@ -104,11 +104,11 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
-    fn str_to_hand(s: &str) -> Hand {
+    fn str_to_hand(s: &str) -> Hand<'_> {
         Hand::new(s, &RegularHandComparer {}, &RegularHandCategorizer {})
     }
 
-    fn str_to_joker_hand(s: &str) -> Hand {
+    fn str_to_joker_hand(s: &str) -> Hand<'_> {
         Hand::new(s, &JokerHandComparer {}, &JokerHandCategorizer {})
     }
 }
\ No newline at end of file
@@ -0,0 +1,97 @@
+mod hand;
+mod char_frequency;
+mod card;
+mod hand_comparer;
+mod hand_categorizer;
+
+use std::str::Lines;
+use common::{Answer, Solution};
+use crate::hand::Hand;
+use crate::hand_categorizer::{HandCategorizer, JokerHandCategorizer, RegularHandCategorizer};
+use crate::hand_comparer::{HandComparer, JokerHandComparer, RegularHandComparer};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    /// A hand can only be created with the comparer and categorizer of a part,
+    /// so parsing the hands is left to the parts.
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(lines: Lines<'_>) -> Self::Input<'_> {
+        lines.collect()
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> Answer {
+        let comparer = RegularHandComparer {};
+        let categorizer = RegularHandCategorizer {};
+        let mut hand_bids: Vec<(Hand, i64)> = lines.iter()
+            .map(|line| parse_line(line, &comparer, &categorizer))
+            .collect();
+        hand_bids.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut score: i64 = 0;
+        for rank in 1..=hand_bids.len() {
+            score += rank as i64 * hand_bids[rank - 1].1;
+        }
+        score.into()
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> Answer {
+        let comparer = JokerHandComparer {};
+        let categorizer = JokerHandCategorizer {};
+        let mut hand_bids: Vec<(Hand, i64)> = lines.iter()
+            .map(|line| parse_line(line, &comparer, &categorizer))
+            .collect();
+        hand_bids.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut score: i64 = 0;
+        for rank in 1..=hand_bids.len() {
+            score += rank as i64 * hand_bids[rank - 1].1;
+        }
+        score.into()
+    }
+}
+
+fn parse_line<'a>(line: &str, comparer: &'a dyn HandComparer, categorizer: &dyn HandCategorizer) -> (Hand<'a>, i64) {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let hand = Hand::new(parts[0], comparer, categorizer);
+    let bid = parts[1].parse::<i64>().unwrap();
+    (hand, bid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"#;
+
+    #[test]
+    fn test_solve_part1()
+    {
+        // Arrange
+        let expected = Answer::from(6440);
+
+        // Act
+        let actual: Answer = Day07::solve_part1(INPUT.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2()
+    {
+        // Arrange
+        let expected = Answer::from(5905);
+
+        // Act
+        let actual: Answer = Day07::solve_part2(INPUT.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
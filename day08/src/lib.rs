@@ -0,0 +1,82 @@
+use std::str::Lines;
+use common::{lcm_all, Answer, Solution};
+use crate::network::Network;
+
+mod network;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Network<'a>;
+
+    fn parse(lines: Lines<'_>) -> Self::Input<'_> {
+        Network::parse(lines)
+    }
+
+    fn part1(network: &Self::Input<'_>) -> Answer {
+        network.count_steps("AAA", |node| node == "ZZZ").into()
+    }
+
+    /// Walking all ghosts step by step takes far too long. Each ghost ends up in a loop where it
+    /// reaches its end node every `n` steps, with `n` equal to the steps to its first end node,
+    /// so all ghosts are on an end node together after the least common multiple of those.
+    fn part2(network: &Self::Input<'_>) -> Answer {
+        let cycle_lengths: Vec<u64> = network.nodes()
+            .filter(|node| node.ends_with('A'))
+            .map(|start| network.count_steps(start, |node| node.ends_with('Z')))
+            .collect();
+        lcm_all(&cycle_lengths)
+            .expect("The number of steps doesn't fit in a u64")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part1() {
+        // Arrange
+        let input = r#"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)"#;
+        let expected = Answer::from(2);
+
+        // Act
+        let actual: Answer = Day08::solve_part1(input.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        // Arrange
+        let input = r#"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"#;
+        let expected = Answer::from(6);
+
+        // Act
+        let actual: Answer = Day08::solve_part2(input.lines());
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,109 @@
+use common::Graph;
+use std::str::Lines;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn parse(ch: char) -> Self {
+        match ch {
+            'L' => Direction::Left,
+            'R' => Direction::Right,
+            _ => panic!("Unknown direction {}", ch)
+        }
+    }
+}
+
+/// The left/right instructions and the network of nodes they are followed through.
+pub struct Network<'a> {
+    instructions: Vec<Direction>,
+    nodes: Graph<&'a str, Direction>,
+}
+
+impl<'a> Network<'a> {
+    pub fn parse(mut lines: Lines<'a>) -> Self {
+        let instructions: Vec<Direction> = lines.next().unwrap().chars().map(Direction::parse).collect();
+        let mut nodes: Graph<&str, Direction> = Graph::new();
+        for line in lines.filter(|line| !line.is_empty()) {
+            let (node, left, right) = parse_node_line(line);
+            nodes.add_edge(node, left, Direction::Left);
+            nodes.add_edge(node, right, Direction::Right);
+        }
+        Network { instructions, nodes }
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.nodes.nodes().copied()
+    }
+
+    /// The node reached from `node` by going in `direction`.
+    pub fn next(&self, node: &'a str, direction: Direction) -> &'a str {
+        self.nodes
+            .neighbours(&node)
+            .find(|(_, edge_direction)| **edge_direction == direction)
+            .map(|(next, _)| *next)
+            .unwrap_or_else(|| panic!("Node {} has no way to go {:?}", node, direction))
+    }
+
+    /// The number of steps needed to get from `start` to a node for which `is_end` holds,
+    /// repeating the instructions as often as needed.
+    pub fn count_steps(&self, start: &'a str, is_end: impl Fn(&str) -> bool) -> u64 {
+        let mut node = start;
+        let mut nr_steps: u64 = 0;
+        for direction in self.instructions.iter().cycle() {
+            if is_end(node) {
+                break;
+            }
+            node = self.next(node, *direction);
+            nr_steps += 1;
+        }
+
+        nr_steps
+    }
+}
+
+/// Parse a line like `AAA = (BBB, CCC)` into the node and its left and right neighbour.
+fn parse_node_line(line: &str) -> (&str, &str, &str) {
+    let (node, neighbours) = line.split_once(" = ").unwrap();
+    let (left, right) = neighbours
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .split_once(", ")
+        .unwrap();
+    (node, left, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_node_line() {
+        assert_eq!(parse_node_line("AAA = (BBB, CCC)"), ("AAA", "BBB", "CCC"));
+    }
+
+    #[test]
+    fn test_next() {
+        // Arrange
+        let network = Network::parse("LR\n\nAAA = (BBB, CCC)\nBBB = (BBB, BBB)\nCCC = (CCC, CCC)".lines());
+
+        // Act & Assert
+        assert_eq!(network.next("AAA", Direction::Left), "BBB");
+        assert_eq!(network.next("AAA", Direction::Right), "CCC");
+    }
+
+    #[test]
+    fn test_count_steps_repeats_instructions() {
+        // Arrange
+        let network = Network::parse("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)".lines());
+
+        // Act
+        let actual = network.count_steps("AAA", |node| node == "ZZZ");
+
+        // Assert
+        assert_eq!(actual, 6);
+    }
+}